@@ -0,0 +1,145 @@
+use hdk::prelude::*;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::type_descriptor::TypeDescriptor;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::descriptor_revisions::{get_latest_revision, update_link_type_for};
+use crate::holon_collection_descriptor_storage_fns::create_holon_collection_descriptor;
+use crate::holon_descriptor_storage_fns::create_holon_descriptor;
+use crate::relationship_descriptor_storage_fns::create_relationship_descriptor;
+use crate::type_name_registry::get_type_name_claim;
+use crate::value_descriptor_storage_fns::create_value_descriptor;
+
+/// Creates every descriptor in `descriptors` within a single zome call, so either all of them
+/// are committed or none are. References that name a type but carry no ActionHash are resolved
+/// to the descriptor of that type_name in the same batch, or else to the one already in the
+/// type_name registry. Descriptors are committed after those they reference, so the batch may
+/// list them in any order, but may not reference each other in a cycle. Returns the created
+/// records, in the order of `descriptors`.
+#[hdk_extern]
+pub fn create_descriptors_batch(descriptors: Vec<TypeDescriptor>) -> ExternResult<Vec<Record>> {
+    let mut created: BTreeMap<String, ActionHash> = BTreeMap::new();
+    let mut records: Vec<Option<Record>> = vec![None; descriptors.len()];
+    for index in creation_order(&descriptors)? {
+        let mut descriptor = descriptors[index].clone();
+        let mut resolved: BTreeMap<String, ActionHash> = BTreeMap::new();
+        for type_name in descriptor.unresolved_reference_names() {
+            let action_hash = match created.get(&type_name) {
+                Some(action_hash) => Some(action_hash.clone()),
+                None => get_type_name_claim(&type_name)?,
+            };
+            if let Some(action_hash) = action_hash {
+                resolved.insert(type_name, action_hash);
+            }
+        }
+        descriptor.resolve_references(&mut |type_name| resolved.get(type_name).cloned());
+
+        let type_name = descriptor.header().type_name.clone();
+        let record = match descriptor {
+            TypeDescriptor::Holon(descriptor) => create_holon_descriptor(descriptor)?,
+            TypeDescriptor::Value(descriptor) => create_value_descriptor(descriptor)?,
+            TypeDescriptor::Relationship(descriptor) => {
+                create_relationship_descriptor(descriptor)?
+            }
+            TypeDescriptor::HolonCollection(descriptor) => {
+                create_holon_collection_descriptor(descriptor)?
+            }
+        };
+        created.insert(type_name, record.action_address().clone());
+        records[index] = Some(record);
+    }
+    Ok(records.into_iter().flatten().collect())
+}
+
+// Orders the batch so that each descriptor follows the descriptors of the batch it references,
+// keeping the order of the batch where references don't decide it
+fn creation_order(descriptors: &[TypeDescriptor]) -> ExternResult<Vec<usize>> {
+    let mut index_of: BTreeMap<&str, usize> = BTreeMap::new();
+    for (index, descriptor) in descriptors.iter().enumerate() {
+        let type_name = descriptor.header().type_name.as_str();
+        if index_of.insert(type_name, index).is_some() {
+            return Err(wasm_error!(WasmErrorInner::Guest(
+                DescriptorsError::DuplicateTypeName(type_name.to_string()).to_string()
+            )));
+        }
+    }
+    let dependencies: Vec<BTreeSet<usize>> = descriptors
+        .iter()
+        .map(|descriptor| {
+            descriptor
+                .unresolved_reference_names()
+                .iter()
+                .filter_map(|type_name| index_of.get(type_name.as_str()).copied())
+                .collect()
+        })
+        .collect();
+
+    let mut placed = vec![false; descriptors.len()];
+    let mut order: Vec<usize> = Vec::new();
+    while order.len() < descriptors.len() {
+        let ready = (0..descriptors.len())
+            .find(|&index| !placed[index] && dependencies[index].iter().all(|&d| placed[d]));
+        match ready {
+            Some(index) => {
+                placed[index] = true;
+                order.push(index);
+            }
+            None => {
+                let cycle = find_unplaced_cycle(descriptors, &dependencies, &placed);
+                return Err(wasm_error!(WasmErrorInner::Guest(
+                    DescriptorsError::CyclicTypeGraph(cycle).to_string()
+                )));
+            }
+        }
+    }
+    Ok(order)
+}
+
+// Every descriptor left unplaced depends on another unplaced one, so following those
+// dependencies from any of them must come back around to a descriptor already visited
+fn find_unplaced_cycle(
+    descriptors: &[TypeDescriptor],
+    dependencies: &[BTreeSet<usize>],
+    placed: &[bool],
+) -> Vec<String> {
+    let mut path: Vec<usize> = vec![placed.iter().position(|&is_placed| !is_placed).unwrap()];
+    loop {
+        let last = path[path.len() - 1];
+        let next = *dependencies[last].iter().find(|&&d| !placed[d]).unwrap();
+        if let Some(start) = path.iter().position(|&index| index == next) {
+            return path[start..]
+                .iter()
+                .chain(std::iter::once(&next))
+                .map(|&index| descriptors[index].header().type_name.clone())
+                .collect();
+        }
+        path.push(next);
+    }
+}
+
+/// Returns the latest revisions of the descriptors whose originals are `original_hashes`, in the
+/// same order, with None for those that cannot be found. The originals are fetched together.
+#[hdk_extern]
+pub fn get_descriptors_batch(
+    original_hashes: Vec<ActionHash>,
+) -> ExternResult<Vec<Option<Record>>> {
+    let get_input: Vec<GetInput> = original_hashes
+        .iter()
+        .map(|action_hash| GetInput::new(action_hash.clone().into(), GetOptions::default()))
+        .collect();
+    let original_records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+
+    let mut records: Vec<Option<Record>> = Vec::new();
+    for (original_hash, original_record) in original_hashes.into_iter().zip(original_records) {
+        let update_link_type = match original_record {
+            Some(original_record) => update_link_type_for(&original_record)?,
+            None => None,
+        };
+        let record = match update_link_type {
+            Some(update_link_type) => get_latest_revision(original_hash, update_link_type)?,
+            None => None,
+        };
+        records.push(record);
+    }
+    Ok(records)
+}
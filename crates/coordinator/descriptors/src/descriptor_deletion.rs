@@ -0,0 +1,252 @@
+use descriptors_integrity::listing_validators::{
+    ALL_HOLON_COLLECTION_TYPES_PATH, ALL_HOLON_TYPES_PATH, ALL_RELATIONSHIP_TYPES_PATH,
+    ALL_VALUE_DESCRIPTORS_PATH,
+};
+use descriptors_integrity::*;
+use hdk::prelude::*;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::type_header::TypeHeader;
+use shared_types_descriptor::validation::{holon_shared_value_references, shared_value_references};
+use std::collections::BTreeSet;
+
+use crate::descriptor_dependents::{unlink_shared_reference, DescriptorDependent};
+use crate::descriptor_index::unindex_descriptor;
+use crate::descriptor_listing::remove_from_listing;
+use crate::descriptor_revisions::{get_latest_revision, get_revision_records, update_link_type_for};
+use crate::helpers::{
+    get_holon_collection_descriptor_from_record, get_relationship_descriptor_from_record,
+};
+use crate::holon_collection_descriptor_queries::get_all_holon_collection_types;
+use crate::relationship_descriptor_queries::get_all_relationship_types;
+
+/// A descriptor is deleted by deleting every one of its revisions, removing it from the
+/// get_all_xxx listing of its kind and dropping the UsedBy links of the shared descriptors it
+/// referenced. Descriptors that other live descriptors depend upon (shared ValueDescriptors used
+/// by composites or holons; HolonDescriptors used by relationships or holon collections) are
+/// only deleted when the DeletionMode says what to do about their dependents. That guard lives
+/// here rather than in validation, so an agent calling delete_entry directly is not held to it.
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeletionMode {
+    // refuse to delete a descriptor that has dependents, listing them in the error
+    #[default]
+    Restrict,
+    // delete the dependents (and theirs, transitively) along with the descriptor
+    Cascade,
+    // delete the descriptor anyway, leaving its dependents with dangling references
+    Force,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DeleteDescriptorInput {
+    pub original_descriptor_hash: ActionHash,
+    pub mode: DeletionMode,
+}
+
+/// Deletes the descriptor whose original is `input.original_descriptor_hash` according to
+/// `input.mode`, returning the original hashes of every descriptor deleted (dependents first).
+/// Fails unless that descriptor is of the kind whose revisions are chained by `update_link_type`.
+pub fn delete_descriptor(
+    input: DeleteDescriptorInput,
+    update_link_type: LinkTypes,
+) -> ExternResult<Vec<ActionHash>> {
+    let original_record = get(input.original_descriptor_hash.clone(), GetOptions::default())?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not find the descriptor being deleted"
+        ))))?;
+    if update_link_type_for(&original_record)? != Some(update_link_type) {
+        return Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "{} is not a descriptor of the kind being deleted",
+            input.original_descriptor_hash
+        ))));
+    }
+    let mut deleted: Vec<ActionHash> = Vec::new();
+    delete_descriptor_and_dependents(input.original_descriptor_hash, input.mode, &mut deleted)?;
+    Ok(deleted)
+}
+
+fn delete_descriptor_and_dependents(
+    original_hash: ActionHash,
+    mode: DeletionMode,
+    deleted: &mut Vec<ActionHash>,
+) -> ExternResult<()> {
+    let original_record = get(original_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the descriptor being deleted"))
+    ))?;
+    let dependents = get_direct_dependents(&original_hash, &original_record)?;
+    match mode {
+        DeletionMode::Restrict => {
+            if !dependents.is_empty() {
+                return Err(wasm_error!(WasmErrorInner::Guest(
+                    DescriptorsError::DescriptorInUse(
+                        original_hash,
+                        dependents
+                            .iter()
+                            .map(|dependent| dependent.property_path.join(" -> "))
+                            .collect(),
+                    )
+                    .to_string()
+                )));
+            }
+        }
+        DeletionMode::Cascade => {
+            for dependent in dependents {
+                if !deleted.contains(&dependent.dependent_hash) {
+                    delete_descriptor_and_dependents(dependent.dependent_hash, mode, deleted)?;
+                }
+            }
+        }
+        DeletionMode::Force => {}
+    }
+    remove_descriptor(&original_hash, &original_record)?;
+    deleted.push(original_hash);
+    Ok(())
+}
+
+/// Returns the live descriptors that reference the descriptor whose original is `original_hash`
+pub fn get_direct_dependents(
+    original_hash: &ActionHash,
+    original_record: &Record,
+) -> ExternResult<Vec<DescriptorDependent>> {
+    let dependents = match entry_types_from_record(original_record)? {
+        Some(EntryTypes::ValueDescriptor(_)) => {
+            get_links(original_hash.clone(), LinkTypes::UsedBy, None)?
+                .into_iter()
+                .map(|link| DescriptorDependent {
+                    dependent_hash: ActionHash::from(link.target),
+                    property_path: vec![String::from_utf8_lossy(&link.tag.0).to_string()],
+                })
+                .collect()
+        }
+        Some(EntryTypes::HolonDescriptor(_)) => get_holon_type_dependents(original_hash)?,
+        _ => Vec::new(),
+    };
+    Ok(dependents)
+}
+
+// HolonDescriptors are referenced by the endpoints of RelationshipDescriptors and the item types
+// of HolonCollectionDescriptors. Neither is indexed by the type it references, so the listings
+// of both kinds are scanned (at their latest revisions).
+fn get_holon_type_dependents(
+    original_hash: &ActionHash,
+) -> ExternResult<Vec<DescriptorDependent>> {
+    let mut dependents = Vec::new();
+    for record in get_all_relationship_types(())? {
+        let relationship_hash = record.action_address().clone();
+        let latest = get_latest_revision(
+            relationship_hash.clone(),
+            LinkTypes::RelationshipDescriptorUpdates,
+        )?;
+        if let Some(latest) = latest {
+            let relationship = get_relationship_descriptor_from_record(latest)?;
+            let type_name = &relationship.header.type_name;
+            for (field, reference) in [
+                ("source_type", &relationship.source_type),
+                ("target_type", &relationship.target_type),
+            ] {
+                if reference.id.as_ref() == Some(original_hash) {
+                    dependents.push(DescriptorDependent {
+                        dependent_hash: relationship_hash.clone(),
+                        property_path: vec![format!("{type_name}.{field}")],
+                    });
+                }
+            }
+        }
+    }
+    for record in get_all_holon_collection_types(())? {
+        let collection_hash = record.action_address().clone();
+        let latest = get_latest_revision(
+            collection_hash.clone(),
+            LinkTypes::HolonCollectionDescriptorUpdates,
+        )?;
+        if let Some(latest) = latest {
+            let collection = get_holon_collection_descriptor_from_record(latest)?;
+            if collection.contains_items_of_type.id.as_ref() == Some(original_hash) {
+                dependents.push(DescriptorDependent {
+                    dependent_hash: collection_hash,
+                    property_path: vec![format!(
+                        "{}.contains_items_of_type",
+                        collection.header.type_name
+                    )],
+                });
+            }
+        }
+    }
+    Ok(dependents)
+}
+
+// Deletes every revision of a descriptor, then the links that list or index it and those that
+// record its references. The Deletes go first, as they are what entitle an agent other than the
+// author of those links to delete them.
+fn remove_descriptor(original_hash: &ActionHash, original_record: &Record) -> ExternResult<()> {
+    let update_link_type = update_link_type_for(original_record)?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Record being deleted is not a descriptor"))
+    ))?;
+    let revisions = get_revision_records(original_hash.clone(), update_link_type)?;
+    // revisions on concurrent branches may reference different shared descriptors
+    let mut references: BTreeSet<(ActionHash, String)> = BTreeSet::new();
+    for (record, _header) in revisions.iter() {
+        let revision_references = match entry_types_from_record(record)? {
+            Some(EntryTypes::ValueDescriptor(descriptor)) => shared_value_references(&descriptor),
+            Some(EntryTypes::HolonDescriptor(descriptor)) => {
+                holon_shared_value_references(&descriptor)
+            }
+            _ => Vec::new(),
+        };
+        for shared in revision_references {
+            if let Some(shared_hash) = shared.reference.id {
+                references.insert((shared_hash, shared.path));
+            }
+        }
+    }
+    let headers: Vec<TypeHeader> =
+        revisions.iter().map(|(_record, header)| header.clone()).collect();
+    for (record, _header) in revisions {
+        delete_entry(record.action_address().clone())?;
+    }
+
+    match entry_types_from_record(original_record)? {
+        Some(EntryTypes::HolonDescriptor(descriptor)) => remove_from_listing(
+            ALL_HOLON_TYPES_PATH,
+            LinkTypes::AllHolonTypes,
+            original_hash,
+            &descriptor.header.type_name,
+        )?,
+        Some(EntryTypes::ValueDescriptor(descriptor)) => remove_from_listing(
+            ALL_VALUE_DESCRIPTORS_PATH,
+            LinkTypes::AllValueDescriptors,
+            original_hash,
+            &descriptor.header.type_name,
+        )?,
+        Some(EntryTypes::RelationshipDescriptor(_)) => remove_from_unsharded_listing(
+            ALL_RELATIONSHIP_TYPES_PATH,
+            LinkTypes::AllRelationshipTypes,
+            original_hash,
+        )?,
+        Some(EntryTypes::HolonCollectionDescriptor(_)) => remove_from_unsharded_listing(
+            ALL_HOLON_COLLECTION_TYPES_PATH,
+            LinkTypes::AllHolonCollectionTypes,
+            original_hash,
+        )?,
+        Some(EntryTypes::Holon(_)) | Some(EntryTypes::TypeNameClaim(_)) | None => {}
+    }
+    for (shared_hash, path) in references {
+        unlink_shared_reference(original_hash, &shared_hash, &path)?;
+    }
+    unindex_descriptor(original_hash, &headers)?;
+    Ok(())
+}
+
+fn remove_from_unsharded_listing(
+    listing: &str,
+    link_type: LinkTypes,
+    original_hash: &ActionHash,
+) -> ExternResult<()> {
+    let path = Path::from(listing);
+    for link in get_links(path.path_entry_hash()?, link_type, None)? {
+        if ActionHash::from(link.target) == *original_hash {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
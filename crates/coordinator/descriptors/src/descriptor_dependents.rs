@@ -0,0 +1,129 @@
+use descriptors_integrity::LinkTypes;
+use hdk::prelude::*;
+use shared_types_descriptor::validation::SharedValueReference;
+use std::collections::{BTreeSet, VecDeque};
+
+/// Each shared ValueDescriptor is linked to every ValueDescriptor and HolonDescriptor that
+/// references it by a UsedBy link, from the original of the shared descriptor to the original of
+/// the dependent, tagged with the property path of the reference. The links are maintained as
+/// dependents are created and updated, so the dependents of a shared type can be found before
+/// changing or deleting it.
+
+/// A descriptor that uses a shared descriptor, directly or through other shared descriptors
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DescriptorDependent {
+    pub dependent_hash: ActionHash,
+    // one property path per hop, from the dependent down to the shared descriptor
+    pub property_path: Vec<String>,
+}
+
+impl DescriptorDependent {
+    /// True if the dependent references the shared descriptor itself
+    pub fn is_direct(&self) -> bool {
+        self.property_path.len() == 1
+    }
+}
+
+// (shared descriptor, property path) pairs for the references that carry an ActionHash
+fn used_by_keys(references: &[SharedValueReference]) -> BTreeSet<(ActionHash, String)> {
+    references
+        .iter()
+        .filter_map(|shared| {
+            shared
+                .reference
+                .id
+                .clone()
+                .map(|action_hash| (action_hash, shared.path.clone()))
+        })
+        .collect()
+}
+
+/// Links each shared descriptor in `references` to the newly created `dependent_hash`
+pub fn link_shared_references(
+    dependent_hash: &ActionHash,
+    references: &[SharedValueReference],
+) -> ExternResult<()> {
+    for (shared_hash, path) in used_by_keys(references) {
+        create_link(
+            shared_hash,
+            dependent_hash.clone(),
+            LinkTypes::UsedBy,
+            LinkTag::new(path),
+        )?;
+    }
+    Ok(())
+}
+
+/// Brings the UsedBy links of an updated dependent in line with its references, removing those
+/// for references the update dropped and adding those for references it introduced
+pub fn relink_shared_references(
+    dependent_hash: &ActionHash,
+    previous_references: &[SharedValueReference],
+    references: &[SharedValueReference],
+) -> ExternResult<()> {
+    let previous_keys = used_by_keys(previous_references);
+    let keys = used_by_keys(references);
+    for (shared_hash, path) in previous_keys.difference(&keys) {
+        unlink_shared_reference(dependent_hash, shared_hash, path)?;
+    }
+    for (shared_hash, path) in keys.difference(&previous_keys) {
+        create_link(
+            shared_hash.clone(),
+            dependent_hash.clone(),
+            LinkTypes::UsedBy,
+            LinkTag::new(path.clone()),
+        )?;
+    }
+    Ok(())
+}
+
+/// Removes the UsedBy link recording that `dependent_hash` uses `shared_hash` at `path`
+pub fn unlink_shared_reference(
+    dependent_hash: &ActionHash,
+    shared_hash: &ActionHash,
+    path: &str,
+) -> ExternResult<()> {
+    let links = get_links(shared_hash.clone(), LinkTypes::UsedBy, Some(LinkTag::new(path)))?;
+    // the tag filter matches by prefix, so the path is compared exactly
+    for link in links {
+        let is_this_use = link.tag.0 == path.as_bytes();
+        if is_this_use && ActionHash::from(link.target) == *dependent_hash {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the dependents of the shared descriptor whose original is `original_hash`, nearest
+/// first. Every direct use is listed; a transitive dependent is listed once, through the first
+/// (shortest) chain of references that reaches it.
+#[hdk_extern]
+pub fn get_descriptor_dependents(
+    original_hash: ActionHash,
+) -> ExternResult<Vec<DescriptorDependent>> {
+    let mut dependents: Vec<DescriptorDependent> = Vec::new();
+    let mut reached: BTreeSet<ActionHash> = BTreeSet::from([original_hash.clone()]);
+    let mut frontier: VecDeque<(ActionHash, Vec<String>)> =
+        VecDeque::from([(original_hash, Vec::new())]);
+
+    while let Some((shared_hash, path_to_shared)) = frontier.pop_front() {
+        let is_root = path_to_shared.is_empty();
+        for link in get_links(shared_hash, LinkTypes::UsedBy, None)? {
+            let dependent_hash = ActionHash::from(link.target);
+            let first_reach = reached.insert(dependent_hash.clone());
+            if !first_reach && !is_root {
+                continue;
+            }
+            let mut property_path = vec![String::from_utf8_lossy(&link.tag.0).to_string()];
+            property_path.extend(path_to_shared.iter().cloned());
+            if first_reach {
+                frontier.push_back((dependent_hash.clone(), property_path.clone()));
+            }
+            dependents.push(DescriptorDependent {
+                dependent_hash,
+                property_path,
+            });
+        }
+    }
+    Ok(dependents)
+}
@@ -0,0 +1,145 @@
+use descriptors_integrity::*;
+use hdk::prelude::*;
+use shared_types_descriptor::descriptor_envelope::DescriptorEnvelope;
+use shared_types_descriptor::holon_descriptor::{HolonCollectionDescriptor, HolonDescriptor};
+use shared_types_descriptor::relationship_descriptor::RelationshipDescriptor;
+use shared_types_descriptor::value_descriptor::ValueDescriptor;
+
+use crate::descriptor_revisions::{get_revision_records, select_heads};
+use crate::holon_collection_descriptor_queries::get_all_holon_collection_types;
+use crate::holon_descriptor_queries::get_all_holon_types;
+use crate::relationship_descriptor_queries::get_all_relationship_types;
+use crate::value_descriptor_queries::get_all_value_descriptors;
+
+/// The externs in this module parallel the get_xxx and get_all_xxx externs, returning each
+/// descriptor in a DescriptorEnvelope rather than as a raw Record.
+
+/// Returns the envelope of the latest revision of the descriptor whose original is
+/// `original_hash`, or None if it does not exist (or has been deleted)
+pub fn get_descriptor_envelope<T>(
+    original_hash: ActionHash,
+    update_link_type: LinkTypes,
+) -> ExternResult<Option<DescriptorEnvelope<T>>>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    let revisions = get_revision_records(original_hash.clone(), update_link_type)?;
+    let original = match revisions
+        .iter()
+        .find(|(record, _header)| *record.action_address() == original_hash)
+    {
+        Some((record, _header)) => record.action().clone(),
+        None => {
+            return Ok(None);
+        }
+    };
+    let (latest, header) = match select_heads(revisions).into_iter().next() {
+        Some(head) => head,
+        None => {
+            return Ok(None);
+        }
+    };
+    let descriptor: T = latest
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Latest revision of the descriptor has no entry"
+        ))))?;
+    Ok(Some(DescriptorEnvelope {
+        descriptor,
+        original_hash,
+        revision_hash: latest.action_address().clone(),
+        author: original.author().clone(),
+        created_at: original.timestamp(),
+        revision_author: latest.action().author().clone(),
+        updated_at: latest.action().timestamp(),
+        version: header.version,
+    }))
+}
+
+// Envelopes the descriptors of the listed original records, in the order listed
+fn get_descriptor_envelopes<T>(
+    original_records: Vec<Record>,
+    update_link_type: LinkTypes,
+) -> ExternResult<Vec<DescriptorEnvelope<T>>>
+where
+    T: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+    let mut envelopes = Vec::new();
+    for record in original_records {
+        let original_hash = record.action_address().clone();
+        if let Some(envelope) = get_descriptor_envelope(original_hash, update_link_type)? {
+            envelopes.push(envelope);
+        }
+    }
+    Ok(envelopes)
+}
+
+#[hdk_extern]
+pub fn get_holon_descriptor_envelope(
+    original_holon_descriptor_hash: ActionHash,
+) -> ExternResult<Option<DescriptorEnvelope<HolonDescriptor>>> {
+    get_descriptor_envelope(original_holon_descriptor_hash, LinkTypes::HolonDescriptorUpdates)
+}
+
+#[hdk_extern]
+pub fn get_value_descriptor_envelope(
+    original_value_descriptor_hash: ActionHash,
+) -> ExternResult<Option<DescriptorEnvelope<ValueDescriptor>>> {
+    get_descriptor_envelope(original_value_descriptor_hash, LinkTypes::ValueDescriptorUpdates)
+}
+
+#[hdk_extern]
+pub fn get_relationship_descriptor_envelope(
+    original_relationship_descriptor_hash: ActionHash,
+) -> ExternResult<Option<DescriptorEnvelope<RelationshipDescriptor>>> {
+    get_descriptor_envelope(
+        original_relationship_descriptor_hash,
+        LinkTypes::RelationshipDescriptorUpdates,
+    )
+}
+
+#[hdk_extern]
+pub fn get_holon_collection_descriptor_envelope(
+    original_holon_collection_descriptor_hash: ActionHash,
+) -> ExternResult<Option<DescriptorEnvelope<HolonCollectionDescriptor>>> {
+    get_descriptor_envelope(
+        original_holon_collection_descriptor_hash,
+        LinkTypes::HolonCollectionDescriptorUpdates,
+    )
+}
+
+#[hdk_extern]
+pub fn get_all_holon_type_envelopes(
+    _: (),
+) -> ExternResult<Vec<DescriptorEnvelope<HolonDescriptor>>> {
+    get_descriptor_envelopes(get_all_holon_types(())?, LinkTypes::HolonDescriptorUpdates)
+}
+
+#[hdk_extern]
+pub fn get_all_value_descriptor_envelopes(
+    _: (),
+) -> ExternResult<Vec<DescriptorEnvelope<ValueDescriptor>>> {
+    get_descriptor_envelopes(get_all_value_descriptors(())?, LinkTypes::ValueDescriptorUpdates)
+}
+
+#[hdk_extern]
+pub fn get_all_relationship_type_envelopes(
+    _: (),
+) -> ExternResult<Vec<DescriptorEnvelope<RelationshipDescriptor>>> {
+    get_descriptor_envelopes(
+        get_all_relationship_types(())?,
+        LinkTypes::RelationshipDescriptorUpdates,
+    )
+}
+
+#[hdk_extern]
+pub fn get_all_holon_collection_type_envelopes(
+    _: (),
+) -> ExternResult<Vec<DescriptorEnvelope<HolonCollectionDescriptor>>> {
+    get_descriptor_envelopes(
+        get_all_holon_collection_types(())?,
+        LinkTypes::HolonCollectionDescriptorUpdates,
+    )
+}
@@ -0,0 +1,169 @@
+use descriptors_integrity::descriptor_index_validators::{base_type_path, search_token_path};
+use descriptors_integrity::*;
+use hdk::prelude::*;
+use shared_types_descriptor::type_descriptor::TypeDescriptor;
+use shared_types_descriptor::type_header::{tokenize, BaseType, TypeHeader};
+use shared_types_descriptor::value_descriptor::ValueDescriptor;
+use std::collections::BTreeSet;
+
+use crate::descriptor_revisions::{get_latest_revision, update_link_type_for};
+
+/// Every descriptor is indexed by its base_type and by the search tokens of its label and
+/// description (see descriptor_index_validators for the layout of the index). The storage
+/// functions keep the index in line with each create, update and delete, so lookups only fetch
+/// the descriptors they return.
+
+/// Adds a newly created descriptor to the index
+pub fn index_descriptor(original_hash: &ActionHash, header: &TypeHeader) -> ExternResult<()> {
+    create_link(
+        base_type_path(&header.base_type).path_entry_hash()?,
+        original_hash.clone(),
+        LinkTypes::DescriptorsOfBaseType,
+        (),
+    )?;
+    for token in header.search_tokens() {
+        link_search_token(original_hash, &token)?;
+    }
+    Ok(())
+}
+
+/// Brings the search tokens of an updated descriptor in line with its label and description.
+/// The base_type cannot change across revisions, so it stays indexed as it was.
+pub fn reindex_descriptor(
+    original_hash: &ActionHash,
+    previous_header: &TypeHeader,
+    header: &TypeHeader,
+) -> ExternResult<()> {
+    let previous_tokens = previous_header.search_tokens();
+    let tokens = header.search_tokens();
+    for token in previous_tokens.difference(&tokens) {
+        unlink_search_token(original_hash, token)?;
+    }
+    for token in tokens.difference(&previous_tokens) {
+        link_search_token(original_hash, token)?;
+    }
+    Ok(())
+}
+
+/// Removes a descriptor that is being deleted from the index, given the headers of all of its
+/// revisions
+pub fn unindex_descriptor(original_hash: &ActionHash, headers: &[TypeHeader]) -> ExternResult<()> {
+    let base_types: BTreeSet<BaseType> =
+        headers.iter().map(|header| header.base_type.clone()).collect();
+    for base_type in base_types {
+        let path = base_type_path(&base_type);
+        let links = get_links(path.path_entry_hash()?, LinkTypes::DescriptorsOfBaseType, None)?;
+        for link in links {
+            if ActionHash::from(link.target) == *original_hash {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+    }
+    let tokens: BTreeSet<String> =
+        headers.iter().flat_map(|header| header.search_tokens()).collect();
+    for token in tokens {
+        unlink_search_token(original_hash, &token)?;
+    }
+    Ok(())
+}
+
+fn link_search_token(original_hash: &ActionHash, token: &str) -> ExternResult<()> {
+    create_link(
+        search_token_path(token).path_entry_hash()?,
+        original_hash.clone(),
+        LinkTypes::DescriptorsByToken,
+        LinkTag::new(token),
+    )?;
+    Ok(())
+}
+
+fn unlink_search_token(original_hash: &ActionHash, token: &str) -> ExternResult<()> {
+    let links = get_links(
+        search_token_path(token).path_entry_hash()?,
+        LinkTypes::DescriptorsByToken,
+        Some(LinkTag::new(token)),
+    )?;
+    // the tag filter matches by prefix, so the token is compared exactly
+    for link in links {
+        if link.tag.0 == token.as_bytes() && ActionHash::from(link.target) == *original_hash {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+// Returns the originals of the descriptors with a search token starting with `token`
+fn find_originals_by_token(token: &str) -> ExternResult<BTreeSet<ActionHash>> {
+    let links = get_links(
+        search_token_path(token).path_entry_hash()?,
+        LinkTypes::DescriptorsByToken,
+        Some(LinkTag::new(token)),
+    )?;
+    Ok(links
+        .into_iter()
+        .map(|link| ActionHash::from(link.target))
+        .collect())
+}
+
+// Fetches the latest revision of each original, ordered by type_name
+fn get_latest_type_descriptors(
+    original_hashes: impl IntoIterator<Item = ActionHash>,
+) -> ExternResult<Vec<TypeDescriptor>> {
+    let mut descriptors: Vec<TypeDescriptor> = Vec::new();
+    for original_hash in original_hashes {
+        let original_record = match get(original_hash.clone(), GetOptions::default())? {
+            Some(record) => record,
+            None => continue,
+        };
+        let update_link_type = match update_link_type_for(&original_record)? {
+            Some(link_type) => link_type,
+            None => continue,
+        };
+        let latest = match get_latest_revision(original_hash, update_link_type)? {
+            Some(record) => record,
+            None => continue,
+        };
+        let descriptor = entry_types_from_record(&latest)?.and_then(|e| e.into_type_descriptor());
+        if let Some(descriptor) = descriptor {
+            descriptors.push(descriptor);
+        }
+    }
+    descriptors.sort_by(|a, b| a.header().type_name.cmp(&b.header().type_name));
+    Ok(descriptors)
+}
+
+/// Returns the latest revisions of the ValueDescriptors of `base_type`, ordered by type_name
+#[hdk_extern]
+pub fn find_value_descriptors_by_base_type(
+    base_type: BaseType,
+) -> ExternResult<Vec<ValueDescriptor>> {
+    let path = base_type_path(&base_type);
+    let links = get_links(path.path_entry_hash()?, LinkTypes::DescriptorsOfBaseType, None)?;
+    let descriptors = get_latest_type_descriptors(
+        links.into_iter().map(|link| ActionHash::from(link.target)),
+    )?;
+    Ok(descriptors
+        .into_iter()
+        .filter_map(|descriptor| match descriptor {
+            TypeDescriptor::Value(value_descriptor) => Some(value_descriptor),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Returns the latest revisions of the descriptors (of any kind) matching every word of `query`,
+/// ordered by type_name. A query word matches a descriptor if some word of its label or
+/// description starts with it, ignoring case. Words shorter than MIN_SEARCH_TOKEN_LENGTH are
+/// ignored, so a query without any longer words matches nothing.
+#[hdk_extern]
+pub fn search_descriptors(query: String) -> ExternResult<Vec<TypeDescriptor>> {
+    let mut matches: Option<BTreeSet<ActionHash>> = None;
+    for token in tokenize(&query) {
+        let token_matches = find_originals_by_token(&token)?;
+        matches = Some(match matches {
+            Some(matches) => matches.intersection(&token_matches).cloned().collect(),
+            None => token_matches,
+        });
+    }
+    get_latest_type_descriptors(matches.unwrap_or_default())
+}
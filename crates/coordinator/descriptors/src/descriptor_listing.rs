@@ -0,0 +1,205 @@
+use descriptors_integrity::listing_validators::{
+    listing_path, listing_shard, listing_shard_path, listing_shards,
+};
+use descriptors_integrity::LinkTypes;
+use hdk::prelude::*;
+use std::cmp::Ordering;
+
+/// The sharded listings of HolonDescriptors and ValueDescriptors (see listing_validators for
+/// their layout). The listing links carry the type_name, creation time and original ActionHash
+/// of every descriptor listed, so only the descriptors on the requested page are fetched.
+///
+/// By type_name, descriptors are ordered by their shard first, so a page is read a shard at a
+/// time, starting from the shard of its cursor. Creation times are spread across every shard, so
+/// each page by creation time costs a scan of the links of the whole listing.
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListingOrder {
+    // by shard, then by type_name within each shard
+    #[default]
+    TypeName,
+    // by the time each descriptor was listed
+    CreatedAt,
+}
+
+/// The position of a descriptor in a listing, under either order
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ListingCursor {
+    pub type_name: String,
+    pub created_at: Timestamp,
+    pub original_hash: ActionHash,
+}
+
+impl ListingCursor {
+    fn from_link(link: Link) -> Self {
+        ListingCursor {
+            type_name: String::from_utf8_lossy(&link.tag.0).to_string(),
+            created_at: link.timestamp,
+            original_hash: ActionHash::from(link.target),
+        }
+    }
+
+    // ties on type_name or creation time are broken by the original ActionHash
+    fn cmp_by(&self, other: &Self, order: ListingOrder) -> Ordering {
+        let ordering = match order {
+            ListingOrder::TypeName => listing_shard(&self.type_name)
+                .cmp(&listing_shard(&other.type_name))
+                .then_with(|| self.type_name.cmp(&other.type_name)),
+            ListingOrder::CreatedAt => self.created_at.cmp(&other.created_at),
+        };
+        ordering.then_with(|| self.original_hash.cmp(&other.original_hash))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ListDescriptorsInput {
+    pub order: ListingOrder,
+    // the most descriptors to return, at least 1
+    pub limit: u32,
+    // the `next` cursor of the previous page, or None for the first page
+    pub after: Option<ListingCursor>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DescriptorPage {
+    // the original records of the descriptors on this page
+    pub records: Vec<Record>,
+    // None if this is the last page
+    pub next: Option<ListingCursor>,
+}
+
+/// Lists a newly created descriptor in the shard of `listing` for its type_name
+pub fn add_to_listing(
+    listing: &str,
+    link_type: LinkTypes,
+    original_hash: &ActionHash,
+    type_name: &str,
+) -> ExternResult<()> {
+    create_link(
+        listing_path(listing, type_name).path_entry_hash()?,
+        original_hash.clone(),
+        link_type,
+        LinkTag::new(type_name),
+    )?;
+    Ok(())
+}
+
+/// Removes a descriptor that is being deleted from `listing`
+pub fn remove_from_listing(
+    listing: &str,
+    link_type: LinkTypes,
+    original_hash: &ActionHash,
+    type_name: &str,
+) -> ExternResult<()> {
+    let path = listing_path(listing, type_name);
+    for link in get_links(path.path_entry_hash()?, link_type, None)? {
+        if ActionHash::from(link.target) == *original_hash {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the position of every descriptor in `listing`, sorted by `order`
+pub fn get_listing_cursors(
+    listing: &str,
+    link_type: LinkTypes,
+    order: ListingOrder,
+) -> ExternResult<Vec<ListingCursor>> {
+    let mut cursors: Vec<ListingCursor> = Vec::new();
+    for shard in listing_shards() {
+        cursors.extend(get_shard_cursors(listing, link_type, &shard)?);
+    }
+    cursors.sort_by(|a, b| a.cmp_by(b, order));
+    Ok(cursors)
+}
+
+fn get_shard_cursors(
+    listing: &str,
+    link_type: LinkTypes,
+    shard: &str,
+) -> ExternResult<Vec<ListingCursor>> {
+    let path = listing_shard_path(listing, shard);
+    let links = get_links(path.path_entry_hash()?, link_type, None)?;
+    Ok(links.into_iter().map(ListingCursor::from_link).collect())
+}
+
+/// Returns the original records of the descriptors at `cursors`, skipping any that are gone
+pub fn get_listed_records(cursors: &[ListingCursor]) -> ExternResult<Vec<Record>> {
+    let get_input: Vec<GetInput> = cursors
+        .iter()
+        .map(|cursor| GetInput::new(cursor.original_hash.clone().into(), GetOptions::default()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    Ok(records.into_iter().flatten().collect())
+}
+
+/// Returns the page of `listing` that follows `input.after`
+pub fn list_descriptors(
+    listing: &str,
+    link_type: LinkTypes,
+    input: ListDescriptorsInput,
+) -> ExternResult<DescriptorPage> {
+    let limit = input.limit.max(1) as usize;
+    let (page, more) = match input.order {
+        ListingOrder::TypeName => {
+            page_by_type_name(listing, link_type, input.after.as_ref(), limit)?
+        }
+        ListingOrder::CreatedAt => {
+            let cursors = get_listing_cursors(listing, link_type, ListingOrder::CreatedAt)?;
+            take_page(cursors, input.after.as_ref(), ListingOrder::CreatedAt, limit)
+        }
+    };
+    let next = if more { page.last().cloned() } else { None };
+    Ok(DescriptorPage {
+        records: get_listed_records(&page)?,
+        next,
+    })
+}
+
+// Reads the shards in order, from the shard of `after`, until the page is full and it is known
+// whether any descriptors follow it
+fn page_by_type_name(
+    listing: &str,
+    link_type: LinkTypes,
+    after: Option<&ListingCursor>,
+    limit: usize,
+) -> ExternResult<(Vec<ListingCursor>, bool)> {
+    let mut shards = listing_shards();
+    shards.sort();
+    let first_shard = after.map(|after| listing_shard(&after.type_name));
+    let mut page: Vec<ListingCursor> = Vec::new();
+    for shard in shards {
+        if first_shard.as_ref().map_or(false, |first_shard| shard < *first_shard) {
+            continue;
+        }
+        let mut cursors = get_shard_cursors(listing, link_type, &shard)?;
+        cursors.sort_by(|a, b| a.cmp_by(b, ListingOrder::TypeName));
+        let (cursors, more) = take_page(cursors, after, ListingOrder::TypeName, limit - page.len());
+        page.extend(cursors);
+        if more {
+            return Ok((page, true));
+        }
+    }
+    Ok((page, false))
+}
+
+// Returns the first `limit` of the sorted `cursors` that follow `after`, and whether any more
+// follow those
+fn take_page(
+    cursors: Vec<ListingCursor>,
+    after: Option<&ListingCursor>,
+    order: ListingOrder,
+    limit: usize,
+) -> (Vec<ListingCursor>, bool) {
+    let start = match after {
+        Some(after) => {
+            cursors.partition_point(|cursor| cursor.cmp_by(after, order) != Ordering::Greater)
+        }
+        None => 0,
+    };
+    let mut page: Vec<ListingCursor> = cursors.into_iter().skip(start).collect();
+    let more = page.len() > limit;
+    page.truncate(limit);
+    (page, more)
+}
@@ -0,0 +1,193 @@
+use descriptors_integrity::LinkTypes;
+use hdk::prelude::*;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::holon_descriptor::{HolonDescriptor, HolonReference};
+use shared_types_descriptor::value_descriptor::{
+    DescriptorSharing, PropertyDescriptorMap, ValueDescriptor, ValueDescriptorDetails,
+    ValueItemType,
+};
+
+use crate::descriptor_revisions::{get_latest_revision, get_revision_records};
+use crate::helpers::{get_holon_descriptor_from_record, get_value_descriptor_from_record};
+
+/// A property whose usage is `DescriptorSharing::Shared` embeds a copy of the shared
+/// ValueDescriptor as it was when the usage was created. Resolving a descriptor replaces each
+/// such copy with the stored descriptor it refers to, recursively, so that callers don't have
+/// to chase the references themselves.
+///
+/// Resolution descends at most `max_depth` levels of shared references; below that the embedded
+/// copies are left as they are. A shared reference back to a descriptor that is already being
+/// resolved fails with DescriptorsError::CyclicSharedReference.
+
+pub const DEFAULT_RESOLUTION_DEPTH: u32 = 8;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResolutionMode {
+    // each shared reference resolves to the latest revision of the shared descriptor
+    #[default]
+    Latest,
+    // each shared reference resolves to the revision whose version matches the embedded copy
+    Pinned,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResolveDescriptorInput {
+    pub original_descriptor_hash: ActionHash,
+    pub mode: ResolutionMode,
+    pub max_depth: Option<u32>, // defaults to DEFAULT_RESOLUTION_DEPTH
+}
+
+#[hdk_extern]
+pub fn get_resolved_value_descriptor(
+    input: ResolveDescriptorInput,
+) -> ExternResult<Option<ValueDescriptor>> {
+    let record = match get_latest_revision(
+        input.original_descriptor_hash.clone(),
+        LinkTypes::ValueDescriptorUpdates,
+    )? {
+        Some(record) => record,
+        None => {
+            return Ok(None);
+        }
+    };
+    let mut descriptor = get_value_descriptor_from_record(record)?;
+    let mut resolver = Resolver::new(&input, input.original_descriptor_hash.clone());
+    let path = descriptor.header.type_name.clone();
+    resolver.resolve_value_descriptor(&path, &mut descriptor, 0)?;
+    Ok(Some(descriptor))
+}
+
+#[hdk_extern]
+pub fn get_resolved_holon_descriptor(
+    input: ResolveDescriptorInput,
+) -> ExternResult<Option<HolonDescriptor>> {
+    let record = match get_latest_revision(
+        input.original_descriptor_hash.clone(),
+        LinkTypes::HolonDescriptorUpdates,
+    )? {
+        Some(record) => record,
+        None => {
+            return Ok(None);
+        }
+    };
+    let mut descriptor = get_holon_descriptor_from_record(record)?;
+    let mut resolver = Resolver::new(&input, input.original_descriptor_hash.clone());
+    let path = descriptor.header.type_name.clone();
+    resolver.resolve_property_map(&path, &mut descriptor.property_map, 0)?;
+    Ok(Some(descriptor))
+}
+
+struct Resolver {
+    mode: ResolutionMode,
+    max_depth: u32,
+    // original hashes of the descriptors currently being resolved, outermost first
+    in_progress: Vec<ActionHash>,
+}
+
+impl Resolver {
+    fn new(input: &ResolveDescriptorInput, root: ActionHash) -> Self {
+        Resolver {
+            mode: input.mode,
+            max_depth: input.max_depth.unwrap_or(DEFAULT_RESOLUTION_DEPTH),
+            in_progress: vec![root],
+        }
+    }
+
+    fn resolve_property_map(
+        &mut self,
+        path: &str,
+        property_map: &mut PropertyDescriptorMap,
+        depth: u32,
+    ) -> ExternResult<()> {
+        for (property_name, usage) in property_map.properties.iter_mut() {
+            let property_path = format!("{path}.{property_name}");
+            match usage.sharing.clone() {
+                DescriptorSharing::Shared(reference) => {
+                    if depth < self.max_depth {
+                        usage.descriptor = self.resolve_shared(
+                            &property_path,
+                            &reference,
+                            &usage.descriptor,
+                            depth,
+                        )?;
+                    }
+                }
+                DescriptorSharing::Dedicated => {
+                    self.resolve_value_descriptor(&property_path, &mut usage.descriptor, depth)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Dedicated descriptors don't add a level of sharing, so they are resolved at `depth`
+    fn resolve_value_descriptor(
+        &mut self,
+        path: &str,
+        descriptor: &mut ValueDescriptor,
+        depth: u32,
+    ) -> ExternResult<()> {
+        match &mut descriptor.details {
+            ValueDescriptorDetails::Composite(composite) => {
+                self.resolve_property_map(path, &mut composite.property_map, depth)
+            }
+            ValueDescriptorDetails::ValueCollection(collection) => {
+                match &mut collection.contains_items_of_type {
+                    ValueItemType::Dedicated(item_descriptor) => self.resolve_value_descriptor(
+                        &format!("{path}.items"),
+                        item_descriptor,
+                        depth,
+                    ),
+                    ValueItemType::Shared(_) => Ok(()),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn resolve_shared(
+        &mut self,
+        path: &str,
+        reference: &HolonReference,
+        embedded: &ValueDescriptor,
+        depth: u32,
+    ) -> ExternResult<ValueDescriptor> {
+        let original_hash = reference.id.clone().ok_or(wasm_error!(WasmErrorInner::Guest(
+            DescriptorsError::UnresolvedReference(path.to_string()).to_string()
+        )))?;
+        if self.in_progress.contains(&original_hash) {
+            return Err(wasm_error!(WasmErrorInner::Guest(
+                DescriptorsError::CyclicSharedReference(path.to_string(), original_hash)
+                    .to_string()
+            )));
+        }
+        let record = match self.mode {
+            ResolutionMode::Latest => {
+                get_latest_revision(original_hash.clone(), LinkTypes::ValueDescriptorUpdates)?
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        DescriptorsError::UnresolvedReference(path.to_string()).to_string()
+                    )))?
+            }
+            ResolutionMode::Pinned => {
+                get_revision_records(original_hash.clone(), LinkTypes::ValueDescriptorUpdates)?
+                    .into_iter()
+                    .find(|(_record, header)| header.version == embedded.header.version)
+                    .map(|(record, _header)| record)
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        DescriptorsError::MissingDescriptorVersion(
+                            path.to_string(),
+                            original_hash.clone(),
+                            embedded.header.version.clone(),
+                        )
+                        .to_string()
+                    )))?
+            }
+        };
+        let mut shared = get_value_descriptor_from_record(record)?;
+        self.in_progress.push(original_hash);
+        let result = self.resolve_value_descriptor(path, &mut shared, depth + 1);
+        self.in_progress.pop();
+        result?;
+        Ok(shared)
+    }
+}
@@ -0,0 +1,300 @@
+use descriptors_integrity::*;
+use hdk::prelude::*;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::type_header::{SemanticVersion, TypeHeader};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Every update of a descriptor is linked from its original (the Create) by a
+/// <Kind>DescriptorUpdates link (e.g. HolonDescriptorUpdates). The functions in this module
+/// gather those links into the revision history of a descriptor.
+///
+/// Each Update names the revision it was derived from, so the revisions form a tree rooted at
+/// the original. Normally that tree is a simple chain, but two agents can concurrently update
+/// the same revision, forking it. The *heads* of the tree are the revisions that no other
+/// revision was derived from; the latest revision is the preferred head, chosen by highest
+/// version, then latest timestamp, then greatest ActionHash, so every agent that sees the same
+/// revisions resolves the same latest revision.
+
+/// A single revision of a descriptor, as recorded in its history
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DescriptorRevision {
+    pub action_hash: ActionHash,
+    // None for the original; otherwise the revision this one was derived from
+    pub previous_action_hash: Option<ActionHash>,
+    pub version: SemanticVersion,
+    pub author: AgentPubKey,
+    pub timestamp: Timestamp,
+}
+
+impl DescriptorRevision {
+    pub fn from_record(record: &Record, header: &TypeHeader) -> Self {
+        DescriptorRevision {
+            action_hash: record.action_address().clone(),
+            previous_action_hash: previous_action_hash(record),
+            version: header.version.clone(),
+            author: record.action().author().clone(),
+            timestamp: record.action().timestamp(),
+        }
+    }
+}
+
+/// Two or more revisions that were concurrently derived from the same previous revision
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DescriptorFork {
+    pub previous_action_hash: ActionHash,
+    pub revision_hashes: Vec<ActionHash>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DescriptorHeads {
+    pub original_action_hash: ActionHash,
+    // ordered by preference, so the first head is the one resolved as the latest revision
+    pub heads: Vec<DescriptorRevision>,
+    pub forks: Vec<DescriptorFork>,
+}
+
+impl DescriptorHeads {
+    /// True if concurrent updates have left more than one head, i.e. the revisions need a merge
+    pub fn is_forked(&self) -> bool {
+        self.heads.len() > 1
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetDescriptorAtVersionInput {
+    pub original_descriptor_hash: ActionHash,
+    pub version: SemanticVersion,
+}
+
+/// Returns the link type used to chain updates to the descriptor held in `record`
+pub fn update_link_type_for(record: &Record) -> ExternResult<Option<LinkTypes>> {
+    let link_type = match entry_types_from_record(record)? {
+        Some(EntryTypes::HolonDescriptor(_)) => Some(LinkTypes::HolonDescriptorUpdates),
+        Some(EntryTypes::ValueDescriptor(_)) => Some(LinkTypes::ValueDescriptorUpdates),
+        Some(EntryTypes::RelationshipDescriptor(_)) => {
+            Some(LinkTypes::RelationshipDescriptorUpdates)
+        }
+        Some(EntryTypes::HolonCollectionDescriptor(_)) => {
+            Some(LinkTypes::HolonCollectionDescriptorUpdates)
+        }
+        Some(EntryTypes::Holon(_)) | Some(EntryTypes::TypeNameClaim(_)) | None => None,
+    };
+    Ok(link_type)
+}
+
+/// Fetches every revision of the descriptor whose original is `original_hash` (including the
+/// original itself), paired with its TypeHeader and ordered by version, then timestamp, then
+/// ActionHash. Revisions that cannot be fetched (e.g., deleted ones) are omitted.
+pub fn get_revision_records(
+    original_hash: ActionHash,
+    update_link_type: LinkTypes,
+) -> ExternResult<Vec<(Record, TypeHeader)>> {
+    let links = get_links(original_hash.clone(), update_link_type, None)?;
+    let get_input: Vec<GetInput> = std::iter::once(original_hash)
+        .chain(links.into_iter().map(|link| ActionHash::from(link.target)))
+        .map(|action_hash| GetInput::new(action_hash.into(), GetOptions::default()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+
+    let mut revisions: Vec<(Record, TypeHeader)> = Vec::new();
+    for record in records.into_iter().flatten() {
+        let header = entry_types_from_record(&record)?.and_then(|e| e.type_header().cloned());
+        if let Some(header) = header {
+            revisions.push((record, header));
+        }
+    }
+    revisions.sort_by(|(record_a, header_a), (record_b, header_b)| {
+        header_a
+            .version
+            .cmp(&header_b.version)
+            .then_with(|| record_a.action().timestamp().cmp(&record_b.action().timestamp()))
+            .then_with(|| record_a.action_address().cmp(record_b.action_address()))
+    });
+    Ok(revisions)
+}
+
+// Orders revisions by preference: highest version, then latest timestamp, then greatest hash
+fn compare_preference(
+    (record_a, header_a): &(Record, TypeHeader),
+    (record_b, header_b): &(Record, TypeHeader),
+) -> std::cmp::Ordering {
+    header_b
+        .version
+        .cmp(&header_a.version)
+        .then_with(|| record_b.action().timestamp().cmp(&record_a.action().timestamp()))
+        .then_with(|| record_b.action_address().cmp(record_a.action_address()))
+}
+
+fn previous_action_hash(record: &Record) -> Option<ActionHash> {
+    match record.action() {
+        Action::Update(update) => Some(update.original_action_address.clone()),
+        _ => None,
+    }
+}
+
+/// Selects the heads among `revisions`, ordered by preference
+pub fn select_heads(revisions: Vec<(Record, TypeHeader)>) -> Vec<(Record, TypeHeader)> {
+    let predecessors: BTreeSet<ActionHash> = revisions
+        .iter()
+        .filter_map(|(record, _header)| previous_action_hash(record))
+        .collect();
+    let mut heads: Vec<(Record, TypeHeader)> = revisions
+        .into_iter()
+        .filter(|(record, _header)| !predecessors.contains(record.action_address()))
+        .collect();
+    heads.sort_by(compare_preference);
+    heads
+}
+
+/// Returns the latest revision of the descriptor whose original is `original_hash`
+pub fn get_latest_revision(
+    original_hash: ActionHash,
+    update_link_type: LinkTypes,
+) -> ExternResult<Option<Record>> {
+    let revisions = get_revision_records(original_hash, update_link_type)?;
+    Ok(select_heads(revisions)
+        .into_iter()
+        .next()
+        .map(|(record, _header)| record))
+}
+
+/// Optimistic concurrency check for updates: fails with DescriptorsError::StaleRevision unless
+/// `previous_hash` is the latest revision of the descriptor whose original is `original_hash`
+pub fn ensure_latest_revision(
+    original_hash: ActionHash,
+    previous_hash: &ActionHash,
+    update_link_type: LinkTypes,
+) -> ExternResult<()> {
+    let latest = get_latest_revision(original_hash, update_link_type)?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the descriptor being updated"))
+    ))?;
+    if latest.action_address() != previous_hash {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            DescriptorsError::StaleRevision(previous_hash.clone(), latest.action_address().clone())
+                .to_string()
+        )));
+    }
+    Ok(())
+}
+
+fn find_forks(revisions: &[(Record, TypeHeader)]) -> Vec<DescriptorFork> {
+    let mut successors: BTreeMap<ActionHash, Vec<ActionHash>> = BTreeMap::new();
+    for (record, _header) in revisions {
+        if let Some(previous) = previous_action_hash(record) {
+            successors
+                .entry(previous)
+                .or_default()
+                .push(record.action_address().clone());
+        }
+    }
+    successors
+        .into_iter()
+        .filter(|(_previous, revision_hashes)| revision_hashes.len() > 1)
+        .map(|(previous_action_hash, revision_hashes)| DescriptorFork {
+            previous_action_hash,
+            revision_hashes,
+        })
+        .collect()
+}
+
+pub fn get_heads(
+    original_hash: ActionHash,
+    update_link_type: LinkTypes,
+) -> ExternResult<DescriptorHeads> {
+    let revisions = get_revision_records(original_hash.clone(), update_link_type)?;
+    let forks = find_forks(&revisions);
+    let heads = select_heads(revisions)
+        .iter()
+        .map(|(record, header)| DescriptorRevision::from_record(record, header))
+        .collect();
+    Ok(DescriptorHeads {
+        original_action_hash: original_hash,
+        heads,
+        forks,
+    })
+}
+
+fn get_history(
+    original_hash: ActionHash,
+    update_link_type: LinkTypes,
+) -> ExternResult<Vec<DescriptorRevision>> {
+    let revisions = get_revision_records(original_hash, update_link_type)?
+        .iter()
+        .map(|(record, header)| DescriptorRevision::from_record(record, header))
+        .collect();
+    Ok(revisions)
+}
+
+#[hdk_extern]
+pub fn get_holon_descriptor_history(
+    original_holon_descriptor_hash: ActionHash,
+) -> ExternResult<Vec<DescriptorRevision>> {
+    get_history(original_holon_descriptor_hash, LinkTypes::HolonDescriptorUpdates)
+}
+
+#[hdk_extern]
+pub fn get_value_descriptor_history(
+    original_value_descriptor_hash: ActionHash,
+) -> ExternResult<Vec<DescriptorRevision>> {
+    get_history(original_value_descriptor_hash, LinkTypes::ValueDescriptorUpdates)
+}
+
+#[hdk_extern]
+pub fn get_relationship_descriptor_history(
+    original_relationship_descriptor_hash: ActionHash,
+) -> ExternResult<Vec<DescriptorRevision>> {
+    get_history(
+        original_relationship_descriptor_hash,
+        LinkTypes::RelationshipDescriptorUpdates,
+    )
+}
+
+#[hdk_extern]
+pub fn get_holon_collection_descriptor_history(
+    original_holon_collection_descriptor_hash: ActionHash,
+) -> ExternResult<Vec<DescriptorRevision>> {
+    get_history(
+        original_holon_collection_descriptor_hash,
+        LinkTypes::HolonCollectionDescriptorUpdates,
+    )
+}
+
+/// Returns the revision of a descriptor that carries exactly `version`
+#[hdk_extern]
+pub fn get_descriptor_at_version(
+    input: GetDescriptorAtVersionInput,
+) -> ExternResult<Option<Record>> {
+    let original_record = match get(input.original_descriptor_hash.clone(), GetOptions::default())?
+    {
+        Some(record) => record,
+        None => {
+            return Ok(None);
+        }
+    };
+    let update_link_type = match update_link_type_for(&original_record)? {
+        Some(link_type) => link_type,
+        None => {
+            return Ok(None);
+        }
+    };
+    let revision = get_revision_records(input.original_descriptor_hash, update_link_type)?
+        .into_iter()
+        .find(|(_record, header)| header.version == input.version)
+        .map(|(record, _header)| record);
+    Ok(revision)
+}
+
+/// Returns every head of a descriptor's revision tree, plus any forks, so that
+/// concurrent updates can be surfaced for a human to merge
+#[hdk_extern]
+pub fn get_descriptor_heads(original_descriptor_hash: ActionHash) -> ExternResult<DescriptorHeads> {
+    let original_record = get(original_descriptor_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not find the original descriptor"
+        ))),
+    )?;
+    let update_link_type = update_link_type_for(&original_record)?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Original record is not a descriptor"))
+    ))?;
+    get_heads(original_descriptor_hash, update_link_type)
+}
@@ -1,5 +1,9 @@
 use hdk::prelude::*;
-use shared_types_descriptor::holon_descriptor::{HolonDescriptor, HolonReference};
+use shared_types_descriptor::holon::Holon;
+use shared_types_descriptor::holon_descriptor::{
+    HolonCollectionDescriptor, HolonDescriptor, HolonReference,
+};
+use shared_types_descriptor::relationship_descriptor::RelationshipDescriptor;
 use shared_types_descriptor::value_descriptor::{
     CompositeDescriptor, DescriptorSharing, ValueDescriptor, ValueDescriptorDetails,
     PropertyDescriptorMap,
@@ -56,6 +60,47 @@ pub fn get_value_descriptor_from_record(record: Record) -> ExternResult<ValueDes
     }
 }
 
+pub fn get_holon_collection_descriptor_from_record(
+    record: Record,
+) -> ExternResult<HolonCollectionDescriptor> {
+    match record.entry() {
+        RecordEntry::Present(entry) => {
+            HolonCollectionDescriptor::try_from(entry.clone()).or(Err(wasm_error!(
+                "Couldn't convert Record entry {:?} into data type {}",
+                entry,
+                std::any::type_name::<HolonCollectionDescriptor>()
+            )))
+        }
+        _ => Err(wasm_error!("Record {:?} does not have an entry", record)),
+    }
+}
+
+pub fn get_relationship_descriptor_from_record(
+    record: Record,
+) -> ExternResult<RelationshipDescriptor> {
+    match record.entry() {
+        RecordEntry::Present(entry) => {
+            RelationshipDescriptor::try_from(entry.clone()).or(Err(wasm_error!(
+                "Couldn't convert Record entry {:?} into data type {}",
+                entry,
+                std::any::type_name::<RelationshipDescriptor>()
+            )))
+        }
+        _ => Err(wasm_error!("Record {:?} does not have an entry", record)),
+    }
+}
+
+pub fn get_holon_from_record(record: Record) -> ExternResult<Holon> {
+    match record.entry() {
+        RecordEntry::Present(entry) => Holon::try_from(entry.clone()).or(Err(wasm_error!(
+            "Couldn't convert Record entry {:?} into data type {}",
+            entry,
+            std::any::type_name::<Holon>()
+        ))),
+        _ => Err(wasm_error!("Record {:?} does not have an entry", record)),
+    }
+}
+
 // TEST HELPERS
 
 // assumes map exists
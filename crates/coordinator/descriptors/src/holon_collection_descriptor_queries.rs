@@ -0,0 +1,16 @@
+use descriptors_integrity::listing_validators::ALL_HOLON_COLLECTION_TYPES_PATH;
+use descriptors_integrity::LinkTypes;
+use hdk::prelude::*;
+
+#[hdk_extern]
+pub fn get_all_holon_collection_types(_: ()) -> ExternResult<Vec<Record>> {
+    let path = Path::from(ALL_HOLON_COLLECTION_TYPES_PATH);
+    let links = get_links(path.path_entry_hash()?, LinkTypes::AllHolonCollectionTypes, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    let records: Vec<Record> = records.into_iter().flatten().collect();
+    Ok(records)
+}
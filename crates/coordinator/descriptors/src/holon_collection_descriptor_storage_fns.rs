@@ -0,0 +1,104 @@
+use descriptors_integrity::listing_validators::ALL_HOLON_COLLECTION_TYPES_PATH;
+use descriptors_integrity::*;
+use hdk::prelude::*;
+use shared_types_descriptor::holon_descriptor::HolonCollectionDescriptor;
+
+use crate::descriptor_deletion::{delete_descriptor, DeleteDescriptorInput};
+use crate::descriptor_index::{index_descriptor, reindex_descriptor};
+use crate::descriptor_revisions::{ensure_latest_revision, get_latest_revision};
+use crate::helpers::get_holon_collection_descriptor_from_record;
+use crate::type_name_registry::{claim_type_name, link_type_name_claim};
+
+/// Creates a HolonCollectionDescriptor, failing if its type_name is already claimed. Claims
+/// another agent has made but this one cannot see yet are not detected (see `claim_type_name`).
+#[hdk_extern]
+pub fn create_holon_collection_descriptor(
+    holon_collection_descriptor: HolonCollectionDescriptor,
+) -> ExternResult<Record> {
+    claim_type_name(&holon_collection_descriptor.header.type_name)?;
+    let holon_collection_descriptor_hash = create_entry(&EntryTypes::HolonCollectionDescriptor(
+        holon_collection_descriptor.clone(),
+    ))?;
+    let record = get(holon_collection_descriptor_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not find the newly created HolonCollectionDescriptor"
+        ))),
+    )?;
+    let path = Path::from(ALL_HOLON_COLLECTION_TYPES_PATH);
+    create_link(
+        path.path_entry_hash()?,
+        holon_collection_descriptor_hash.clone(),
+        LinkTypes::AllHolonCollectionTypes,
+        (),
+    )?;
+    link_type_name_claim(
+        &holon_collection_descriptor.header.type_name,
+        holon_collection_descriptor_hash.clone(),
+    )?;
+    index_descriptor(&holon_collection_descriptor_hash, &holon_collection_descriptor.header)?;
+    Ok(record)
+}
+#[hdk_extern]
+pub fn get_holon_collection_descriptor(
+    original_holon_collection_descriptor_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+    get_latest_revision(
+        original_holon_collection_descriptor_hash,
+        LinkTypes::HolonCollectionDescriptorUpdates,
+    )
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateHolonCollectionDescriptorInput {
+    pub original_holon_collection_descriptor_hash: ActionHash,
+    pub previous_holon_collection_descriptor_hash: ActionHash,
+    pub updated_holon_collection_descriptor: HolonCollectionDescriptor,
+}
+#[hdk_extern]
+pub fn update_holon_collection_descriptor(
+    input: UpdateHolonCollectionDescriptorInput,
+) -> ExternResult<Record> {
+    ensure_latest_revision(
+        input.original_holon_collection_descriptor_hash.clone(),
+        &input.previous_holon_collection_descriptor_hash,
+        LinkTypes::HolonCollectionDescriptorUpdates,
+    )?;
+    let previous_record = get(
+        input.previous_holon_collection_descriptor_hash.clone(),
+        GetOptions::default(),
+    )?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+        "Could not find the previous revision of the HolonCollectionDescriptor"
+    ))))?;
+    let previous_holon_collection_descriptor =
+        get_holon_collection_descriptor_from_record(previous_record)?;
+    let updated_holon_collection_descriptor_hash = update_entry(
+        input.previous_holon_collection_descriptor_hash.clone(),
+        &input.updated_holon_collection_descriptor,
+    )?;
+    create_link(
+        input.original_holon_collection_descriptor_hash.clone(),
+        updated_holon_collection_descriptor_hash.clone(),
+        LinkTypes::HolonCollectionDescriptorUpdates,
+        (),
+    )?;
+    reindex_descriptor(
+        &input.original_holon_collection_descriptor_hash,
+        &previous_holon_collection_descriptor.header,
+        &input.updated_holon_collection_descriptor.header,
+    )?;
+    let record = get(
+        updated_holon_collection_descriptor_hash.clone(),
+        GetOptions::default(),
+    )?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+        "Could not find the newly updated HolonCollectionDescriptor"
+    ))))?;
+    Ok(record)
+}
+/// Deletes a HolonCollectionDescriptor along with all of its revisions (see DeletionMode)
+#[hdk_extern]
+pub fn delete_holon_collection_descriptor(
+    input: DeleteDescriptorInput,
+) -> ExternResult<Vec<ActionHash>> {
+    delete_descriptor(input, LinkTypes::HolonCollectionDescriptorUpdates)
+}
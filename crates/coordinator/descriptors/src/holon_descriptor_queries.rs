@@ -1,8 +1,14 @@
 // use std::collections::BTreeMap;
 
+use descriptors_integrity::listing_validators::ALL_HOLON_TYPES_PATH;
 use descriptors_integrity::LinkTypes;
 use hdk::prelude::*;
 
+use crate::descriptor_listing::{
+    get_listed_records, get_listing_cursors, list_descriptors, DescriptorPage,
+    ListDescriptorsInput, ListingOrder,
+};
+
 // #[hdk_extern]
 // pub fn get_all_holontypes(_: ()) -> ExternResult<Vec<HolonDescriptor>> {
 //     let dummy_data_result = create_dummy_data(());
@@ -15,15 +21,19 @@ use hdk::prelude::*;
 
 // the following was generated by scaffolding tool, notice use of Record in return type
 
+/// Returns every HolonDescriptor, in the order they were created. Use `list_holon_types` to
+/// page through larger registries.
 #[hdk_extern]
 pub fn get_all_holon_types(_: ()) -> ExternResult<Vec<Record>> {
-    let path = Path::from("all_holon_types");
-    let links = get_links(path.path_entry_hash()?, LinkTypes::AllHolonTypes, None)?;
-    let get_input: Vec<GetInput> = links
-        .into_iter()
-        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
-        .collect();
-    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
-    let records: Vec<Record> = records.into_iter().filter_map(|r| r).collect();
-    Ok(records)
+    let cursors = get_listing_cursors(
+        ALL_HOLON_TYPES_PATH,
+        LinkTypes::AllHolonTypes,
+        ListingOrder::CreatedAt,
+    )?;
+    get_listed_records(&cursors)
+}
+
+#[hdk_extern]
+pub fn list_holon_types(input: ListDescriptorsInput) -> ExternResult<DescriptorPage> {
+    list_descriptors(ALL_HOLON_TYPES_PATH, LinkTypes::AllHolonTypes, input)
 }
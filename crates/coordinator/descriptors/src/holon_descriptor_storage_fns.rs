@@ -1,9 +1,22 @@
+use descriptors_integrity::listing_validators::ALL_HOLON_TYPES_PATH;
 use descriptors_integrity::*;
 use hdk::prelude::*;
 use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+use shared_types_descriptor::validation::holon_shared_value_references;
 
+use crate::descriptor_deletion::{delete_descriptor, DeleteDescriptorInput};
+use crate::descriptor_dependents::{link_shared_references, relink_shared_references};
+use crate::descriptor_index::{index_descriptor, reindex_descriptor};
+use crate::descriptor_listing::add_to_listing;
+use crate::descriptor_revisions::{ensure_latest_revision, get_latest_revision};
+use crate::helpers::get_holon_descriptor_from_record;
+use crate::type_name_registry::{claim_type_name, link_type_name_claim};
+
+/// Creates a HolonDescriptor, failing if its type_name is already claimed. Claims another agent
+/// has made but this one cannot see yet are not detected (see `claim_type_name`).
 #[hdk_extern]
 pub fn create_holon_descriptor(holon_descriptor: HolonDescriptor) -> ExternResult<Record> {
+    claim_type_name(&holon_descriptor.header.type_name)?;
     let holon_descriptor_hash =
         create_entry(&EntryTypes::HolonDescriptor(holon_descriptor.clone()))?;
     let record = get(holon_descriptor_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
@@ -11,32 +24,25 @@ pub fn create_holon_descriptor(holon_descriptor: HolonDescriptor) -> ExternResul
             "Could not find the newly created HolonDescriptor"
         ))
     ))?;
-    let path = Path::from("all_holon_types");
-    create_link(
-        path.path_entry_hash()?,
-        holon_descriptor_hash.clone(),
+    add_to_listing(
+        ALL_HOLON_TYPES_PATH,
         LinkTypes::AllHolonTypes,
-        (),
+        &holon_descriptor_hash,
+        &holon_descriptor.header.type_name,
+    )?;
+    link_type_name_claim(&holon_descriptor.header.type_name, holon_descriptor_hash.clone())?;
+    link_shared_references(
+        &holon_descriptor_hash,
+        &holon_shared_value_references(&holon_descriptor),
     )?;
+    index_descriptor(&holon_descriptor_hash, &holon_descriptor.header)?;
     Ok(record)
 }
 #[hdk_extern]
 pub fn get_holon_descriptor(
     original_holon_descriptor_hash: ActionHash,
 ) -> ExternResult<Option<Record>> {
-    let links = get_links(
-        original_holon_descriptor_hash.clone(),
-        LinkTypes::HolonDescriptorUpdates,
-        None,
-    )?;
-    let latest_link = links
-        .into_iter()
-        .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-    let latest_holon_descriptor_hash = match latest_link {
-        Some(link) => ActionHash::from(link.target.clone()),
-        None => original_holon_descriptor_hash.clone(),
-    };
-    get(latest_holon_descriptor_hash, GetOptions::default())
+    get_latest_revision(original_holon_descriptor_hash, LinkTypes::HolonDescriptorUpdates)
 }
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UpdateHolonDescriptorInput {
@@ -46,6 +52,19 @@ pub struct UpdateHolonDescriptorInput {
 }
 #[hdk_extern]
 pub fn update_holon_descriptor(input: UpdateHolonDescriptorInput) -> ExternResult<Record> {
+    ensure_latest_revision(
+        input.original_holon_descriptor_hash.clone(),
+        &input.previous_holon_descriptor_hash,
+        LinkTypes::HolonDescriptorUpdates,
+    )?;
+    let previous_record = get(
+        input.previous_holon_descriptor_hash.clone(),
+        GetOptions::default(),
+    )?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+        "Could not find the previous revision of the HolonDescriptor"
+    ))))?;
+    let previous_holon_descriptor = get_holon_descriptor_from_record(previous_record)?;
     let updated_holon_descriptor_hash = update_entry(
         input.previous_holon_descriptor_hash.clone(),
         &input.updated_holon_descriptor,
@@ -56,6 +75,16 @@ pub fn update_holon_descriptor(input: UpdateHolonDescriptorInput) -> ExternResul
         LinkTypes::HolonDescriptorUpdates,
         (),
     )?;
+    relink_shared_references(
+        &input.original_holon_descriptor_hash,
+        &holon_shared_value_references(&previous_holon_descriptor),
+        &holon_shared_value_references(&input.updated_holon_descriptor),
+    )?;
+    reindex_descriptor(
+        &input.original_holon_descriptor_hash,
+        &previous_holon_descriptor.header,
+        &input.updated_holon_descriptor.header,
+    )?;
     let record = get(updated_holon_descriptor_hash.clone(), GetOptions::default())?.ok_or(
         wasm_error!(WasmErrorInner::Guest(String::from(
             "Could not find the newly updated HolonDescriptor"
@@ -63,9 +92,8 @@ pub fn update_holon_descriptor(input: UpdateHolonDescriptorInput) -> ExternResul
     )?;
     Ok(record)
 }
+/// Deletes a HolonDescriptor along with all of its revisions (see DeletionMode)
 #[hdk_extern]
-pub fn delete_holon_descriptor(
-    original_holon_descriptor_hash: ActionHash,
-) -> ExternResult<ActionHash> {
-    delete_entry(original_holon_descriptor_hash)
+pub fn delete_holon_descriptor(input: DeleteDescriptorInput) -> ExternResult<Vec<ActionHash>> {
+    delete_descriptor(input, LinkTypes::HolonDescriptorUpdates)
 }
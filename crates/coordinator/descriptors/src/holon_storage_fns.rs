@@ -0,0 +1,107 @@
+use descriptors_integrity::*;
+use hdk::prelude::*;
+use shared_types_descriptor::holon::Holon;
+
+/// Every Holon is linked from the original ActionHash of its HolonDescriptor by a HolonsOfType
+/// link, so all of the instances of a type can be listed.
+///
+/// Holons are revised by chaining Updates (each derived from the previous revision). Unlike
+/// descriptors, they carry no version, so the latest revision is found by following the
+/// updates of each revision, preferring the latest timestamp, then the greatest ActionHash.
+
+#[hdk_extern]
+pub fn create_holon(holon: Holon) -> ExternResult<Record> {
+    let holon_hash = create_entry(&EntryTypes::Holon(holon.clone()))?;
+    let record = get(holon_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly created Holon"))
+    ))?;
+    create_link(
+        holon.descriptor.clone(),
+        holon_hash,
+        LinkTypes::HolonsOfType,
+        (),
+    )?;
+    Ok(record)
+}
+#[hdk_extern]
+pub fn get_holon(original_holon_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let mut details = match get_details(original_holon_hash, GetOptions::default())? {
+        Some(Details::Record(details)) => details,
+        _ => {
+            return Ok(None);
+        }
+    };
+    loop {
+        let latest_update = details
+            .updates
+            .iter()
+            .max_by(|a, b| {
+                a.action()
+                    .timestamp()
+                    .cmp(&b.action().timestamp())
+                    .then_with(|| a.as_hash().cmp(b.as_hash()))
+            })
+            .map(|update| update.as_hash().clone());
+        let latest_update = match latest_update {
+            Some(hash) => hash,
+            None => {
+                return Ok(Some(details.record));
+            }
+        };
+        details = match get_details(latest_update, GetOptions::default())? {
+            Some(Details::Record(next)) => next,
+            _ => {
+                return Ok(Some(details.record));
+            }
+        };
+    }
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateHolonInput {
+    pub original_holon_hash: ActionHash,
+    pub previous_holon_hash: ActionHash,
+    pub updated_holon: Holon,
+}
+#[hdk_extern]
+pub fn update_holon(input: UpdateHolonInput) -> ExternResult<Record> {
+    let updated_holon_hash = update_entry(input.previous_holon_hash, &input.updated_holon)?;
+    let record = get(updated_holon_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the newly updated Holon"))
+    ))?;
+    Ok(record)
+}
+#[hdk_extern]
+pub fn delete_holon(original_holon_hash: ActionHash) -> ExternResult<ActionHash> {
+    let record = get(original_holon_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest(String::from("Could not find the Holon to delete"))
+    ))?;
+    let holon: Holon = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Record to delete is not a Holon"
+        ))))?;
+    // the Delete must come first, as it is what entitles this agent to delete the link
+    let delete_hash = delete_entry(original_holon_hash.clone())?;
+    let links = get_links(holon.descriptor, LinkTypes::HolonsOfType, None)?;
+    for link in links {
+        if ActionHash::from(link.target) == original_holon_hash {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(delete_hash)
+}
+/// Returns the latest revision of every holon of the type whose HolonDescriptor was originally
+/// created at `original_holon_descriptor_hash`
+#[hdk_extern]
+pub fn get_holons_of_type(original_holon_descriptor_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let links = get_links(original_holon_descriptor_hash, LinkTypes::HolonsOfType, None)?;
+    let mut records = Vec::new();
+    for link in links {
+        if let Some(record) = get_holon(ActionHash::from(link.target))? {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
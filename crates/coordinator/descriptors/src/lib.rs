@@ -1,10 +1,25 @@
+pub mod descriptor_batch;
+pub mod descriptor_deletion;
+pub mod descriptor_dependents;
+pub mod descriptor_envelopes;
+pub mod descriptor_index;
+pub mod descriptor_listing;
+pub mod descriptor_resolution;
+pub mod descriptor_revisions;
 pub mod helpers;
+pub mod holon_collection_descriptor_queries;
+pub mod holon_collection_descriptor_storage_fns;
+pub mod holon_storage_fns;
 pub mod holon_descriptor_queries;
 pub mod holon_descriptor_storage_fns;
 pub mod mutators;
+pub mod relationship_descriptor_queries;
+pub mod relationship_descriptor_storage_fns;
+pub mod schema_bundles;
 pub mod value_descriptor_queries;
 pub mod value_descriptor_storage_fns;
 pub mod property_map_builder;
+pub mod type_name_registry;
 
 
 use descriptors_integrity::*;
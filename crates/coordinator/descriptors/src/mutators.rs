@@ -4,13 +4,29 @@
 use std::collections::BTreeMap;
 
 // use async_std::stream::StreamExt;
+use hdk::prelude::ActionHash;
+use shared_types_descriptor::base_value::BaseValue;
 use shared_types_descriptor::error::DescriptorsError;
-use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+use shared_types_descriptor::holon::Holon;
+use shared_types_descriptor::holon_descriptor::{
+    HolonCollectionDescriptor, HolonDescriptor, HolonReference,
+};
+use shared_types_descriptor::relationship_descriptor::{DeletionSemantic, RelationshipDescriptor};
 use shared_types_descriptor::value_descriptor::{
-    BooleanDescriptor, CompositeDescriptor, DescriptorSharing, IntegerDescriptor,
-    ValueDescriptor, ValueDescriptorDetails, PropertyDescriptorMap, StringDescriptor,
+    BooleanDescriptor, CompositeDescriptor, DescriptorSharing, EnumDescriptor, EnumVariant,
+    IntegerDescriptor, ValueDescriptor, ValueDescriptorDetails, PropertyDescriptorMap,
+    StringDescriptor, StringFormat, ValueCollectionDescriptor, ValueItemType,
 };
 use shared_types_descriptor::type_header::{BaseType, SemanticVersion, TypeHeader};
+use shared_types_descriptor::validation::{
+    validate_acyclic_value_descriptor, validate_holon_collection_descriptor,
+    validate_relationship_descriptor, validate_value_descriptor,
+};
+use shared_types_descriptor::value_validation::validate_holon_property_values;
+use shared_types_descriptor::versioning::{
+    classify_holon_collection_descriptor_change, classify_holon_descriptor_change,
+    classify_relationship_descriptor_change, classify_value_descriptor_change, next_version,
+};
 
 /// new_xxx_descriptor () functions stage new (empty) instances of Descriptors, but do NOT
 /// commit them to persistent storage.
@@ -69,7 +85,6 @@ fn new_property_descriptor(
     is_dependent: bool,
     details: ValueDescriptorDetails,
 ) -> Result<ValueDescriptor, DescriptorsError> {
-    // Guard that base_type in header matches details
     let header = new_type_header(
         type_name.to_string(),
         base_type,
@@ -77,12 +92,15 @@ fn new_property_descriptor(
         label,
         is_dependent,
     )?;
-    Ok(ValueDescriptor::new(
+    let descriptor = ValueDescriptor::new(
         header,
         // Default is Dedicated
         //DescriptorSharing::default(), // NOTE: will need to change this in the future to accomodate shared or make a seperate function
         details,
-    ))
+    );
+    // Guard that base_type in header matches details (including nested composites)
+    validate_value_descriptor(&descriptor)?;
+    Ok(descriptor)
 }
 
 ///
@@ -167,6 +185,172 @@ pub fn new_boolean_descriptor(
     Ok(desc)
 }
 
+/// Creates an Enum Value Descriptor whose variants are given in their intrinsic order
+pub fn new_enum_descriptor(
+    type_name: String,
+    description: String,
+    label: String,
+    is_dependent: bool,
+    variants: Vec<EnumVariant>,
+) -> Result<ValueDescriptor, DescriptorsError> {
+    let details = ValueDescriptorDetails::Enum(EnumDescriptor::new(variants));
+    let desc = new_property_descriptor(
+        type_name,
+        description,
+        label,
+        BaseType::Enum,
+        is_dependent,
+        details,
+    )?;
+    Ok(desc)
+}
+
+/// Creates a ValueCollection Value Descriptor whose items are values of the type given by
+/// `contains_items_of_type`
+#[allow(clippy::too_many_arguments)]
+pub fn new_value_collection_descriptor(
+    type_name: String,
+    description: String,
+    label: String,
+    is_dependent: bool,
+    contains_items_of_type: ValueItemType,
+    min_items: u32,
+    max_items: u32,
+    unique_items: bool,
+    is_ordered: bool,
+) -> Result<ValueDescriptor, DescriptorsError> {
+    let details = ValueDescriptorDetails::ValueCollection(ValueCollectionDescriptor::new(
+        contains_items_of_type,
+        min_items,
+        max_items,
+        unique_items,
+        is_ordered,
+    ));
+    let desc = new_property_descriptor(
+        type_name,
+        description,
+        label,
+        BaseType::Collection,
+        is_dependent,
+        details,
+    )?;
+    Ok(desc)
+}
+
+/// Creates a RelationshipDescriptor from `source_type` to `target_type`. Both references must
+/// identify a HolonDescriptor by ActionHash.
+///
+#[allow(clippy::too_many_arguments)]
+pub fn new_relationship_descriptor(
+    type_name: String,
+    description: String,
+    label: String,
+    source_type: HolonReference,
+    target_type: HolonReference,
+    min_target_cardinality: u32,
+    max_target_cardinality: u32,
+    inverse_name: Option<String>,
+    is_ordered: bool,
+    deletion_semantic: DeletionSemantic,
+) -> Result<RelationshipDescriptor, DescriptorsError> {
+    let header = new_type_header(type_name, BaseType::Relationship, description, label, false)?;
+    let descriptor = RelationshipDescriptor::new(
+        header,
+        source_type,
+        target_type,
+        min_target_cardinality,
+        max_target_cardinality,
+        inverse_name,
+        is_ordered,
+        deletion_semantic,
+    );
+    validate_relationship_descriptor(&descriptor)?;
+    Ok(descriptor)
+}
+
+/// Creates a HolonCollectionDescriptor whose items are holons of the type identified (by
+/// ActionHash) by `contains_items_of_type`.
+///
+#[allow(clippy::too_many_arguments)]
+pub fn new_holon_collection_descriptor(
+    type_name: String,
+    description: String,
+    label: String,
+    is_dependent: bool,
+    contains_items_of_type: HolonReference,
+    min_items: u32,
+    max_items: u32,
+    unique_items: bool,
+    is_ordered: bool,
+) -> Result<HolonCollectionDescriptor, DescriptorsError> {
+    let header = new_type_header(
+        type_name,
+        BaseType::Collection,
+        description,
+        label,
+        is_dependent,
+    )?;
+    let descriptor = HolonCollectionDescriptor::new(
+        header,
+        contains_items_of_type,
+        min_items,
+        max_items,
+        unique_items,
+        is_ordered,
+    );
+    validate_holon_collection_descriptor(&descriptor)?;
+    Ok(descriptor)
+}
+
+/// Checks that a staged descriptor does not contain itself through the descriptors embedded
+/// within it. Callers that assemble property maps by hand should call it before committing;
+/// cycles through stored shared descriptors are caught by the integrity zome.
+pub fn check_acyclic(descriptor: &ValueDescriptor) -> Result<(), DescriptorsError> {
+    validate_acyclic_value_descriptor(descriptor)
+}
+
+/// bump_xxx_descriptor_version () functions set the version of an updated descriptor to the
+/// next version of its original appropriate to the kind of change between them (see ChangeKind).
+/// The update_xxx_descriptor () functions apply them automatically; callers that revise a
+/// descriptor by hand (e.g., by editing its property map) should call them before committing.
+///
+pub fn bump_holon_descriptor_version(
+    original_descriptor: &HolonDescriptor,
+    updated_descriptor: &mut HolonDescriptor,
+) -> Result<(), DescriptorsError> {
+    let change = classify_holon_descriptor_change(original_descriptor, updated_descriptor);
+    updated_descriptor.header.version = next_version(&original_descriptor.header.version, change)?;
+    Ok(())
+}
+
+pub fn bump_value_descriptor_version(
+    original_descriptor: &ValueDescriptor,
+    updated_descriptor: &mut ValueDescriptor,
+) -> Result<(), DescriptorsError> {
+    let change = classify_value_descriptor_change(original_descriptor, updated_descriptor);
+    updated_descriptor.header.version = next_version(&original_descriptor.header.version, change)?;
+    Ok(())
+}
+
+pub fn bump_relationship_descriptor_version(
+    original_descriptor: &RelationshipDescriptor,
+    updated_descriptor: &mut RelationshipDescriptor,
+) -> Result<(), DescriptorsError> {
+    let change = classify_relationship_descriptor_change(original_descriptor, updated_descriptor);
+    updated_descriptor.header.version = next_version(&original_descriptor.header.version, change)?;
+    Ok(())
+}
+
+pub fn bump_holon_collection_descriptor_version(
+    original_descriptor: &HolonCollectionDescriptor,
+    updated_descriptor: &mut HolonCollectionDescriptor,
+) -> Result<(), DescriptorsError> {
+    let change =
+        classify_holon_collection_descriptor_change(original_descriptor, updated_descriptor);
+    updated_descriptor.header.version = next_version(&original_descriptor.header.version, change)?;
+    Ok(())
+}
+
 pub fn update_boolean_descriptor(
     original_descriptor: &ValueDescriptor,
     new_description: Option<String>,
@@ -194,10 +378,7 @@ pub fn update_boolean_descriptor(
     }
 
     updated_descriptor.details = ValueDescriptorDetails::Boolean(bool_descriptor);
-    println!(
-        "testing update boolean_descriptor: {:#?}",
-        updated_descriptor
-    );
+    bump_value_descriptor_version(original_descriptor, &mut updated_descriptor)?;
 
     Ok(updated_descriptor)
 }
@@ -208,6 +389,8 @@ pub fn update_string_descriptor(
     new_label: Option<String>,
     min_length: Option<u32>,
     max_length: Option<u32>,
+    pattern: Option<Option<String>>,
+    format: Option<Option<StringFormat>>,
 ) -> Result<ValueDescriptor, DescriptorsError> {
     let mut updated_descriptor = original_descriptor.clone();
     if let Some(description) = new_description {
@@ -229,15 +412,24 @@ pub fn update_string_descriptor(
             } else {
                 string_descriptor.max_length = descriptor.max_length
             }
+            // Some(None) clears the constraint, None leaves it unchanged
+            if let Some(pattern) = pattern {
+                string_descriptor.pattern = pattern
+            } else {
+                string_descriptor.pattern = descriptor.pattern
+            }
+            if let Some(format) = format {
+                string_descriptor.format = format
+            } else {
+                string_descriptor.format = descriptor.format
+            }
         }
         _ => panic!("Expected StringDescriptor"),
     }
 
     updated_descriptor.details = ValueDescriptorDetails::String(string_descriptor);
-    println!(
-        "testing update string_descriptor: {:#?}",
-        updated_descriptor
-    );
+    bump_value_descriptor_version(original_descriptor, &mut updated_descriptor)?;
+    validate_value_descriptor(&updated_descriptor)?;
 
     Ok(updated_descriptor)
 }
@@ -274,14 +466,139 @@ pub fn update_integer_descriptor(
     }
 
     updated_descriptor.details = ValueDescriptorDetails::Integer(integer_descriptor);
+    bump_value_descriptor_version(original_descriptor, &mut updated_descriptor)?;
     // println!(
     //     "testing update string_descriptor: {:#?}",
     //     updated_descriptor
     // );
+    validate_value_descriptor(&updated_descriptor)?;
+
+    Ok(updated_descriptor)
+}
+
+pub fn update_enum_descriptor(
+    original_descriptor: &ValueDescriptor,
+    new_description: Option<String>,
+    new_label: Option<String>,
+    variants: Option<Vec<EnumVariant>>,
+) -> Result<ValueDescriptor, DescriptorsError> {
+    let mut updated_descriptor = original_descriptor.clone();
+    if let Some(description) = new_description {
+        updated_descriptor.header.description = description;
+    }
+    if let Some(label) = new_label {
+        updated_descriptor.header.label = label;
+    }
+    let enum_descriptor = match original_descriptor.details.clone() {
+        ValueDescriptorDetails::Enum(descriptor) => {
+            if let Some(variants) = variants {
+                EnumDescriptor::new(variants)
+            } else {
+                descriptor
+            }
+        }
+        _ => panic!("Expected EnumDescriptor"),
+    };
+
+    updated_descriptor.details = ValueDescriptorDetails::Enum(enum_descriptor);
+    bump_value_descriptor_version(original_descriptor, &mut updated_descriptor)?;
+    validate_value_descriptor(&updated_descriptor)?;
+
+    Ok(updated_descriptor)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_relationship_descriptor(
+    original_descriptor: &RelationshipDescriptor,
+    new_description: Option<String>,
+    new_label: Option<String>,
+    min_target_cardinality: Option<u32>,
+    max_target_cardinality: Option<u32>,
+    inverse_name: Option<Option<String>>,
+    is_ordered: Option<bool>,
+    deletion_semantic: Option<DeletionSemantic>,
+) -> Result<RelationshipDescriptor, DescriptorsError> {
+    let mut updated_descriptor = original_descriptor.clone();
+    if let Some(description) = new_description {
+        updated_descriptor.header.description = description;
+    }
+    if let Some(label) = new_label {
+        updated_descriptor.header.label = label;
+    }
+    if let Some(min) = min_target_cardinality {
+        updated_descriptor.min_target_cardinality = min;
+    }
+    if let Some(max) = max_target_cardinality {
+        updated_descriptor.max_target_cardinality = max;
+    }
+    // Some(None) clears the inverse name, None leaves it unchanged
+    if let Some(inverse_name) = inverse_name {
+        updated_descriptor.inverse_name = inverse_name;
+    }
+    if let Some(is_ordered) = is_ordered {
+        updated_descriptor.is_ordered = is_ordered;
+    }
+    if let Some(deletion_semantic) = deletion_semantic {
+        updated_descriptor.deletion_semantic = deletion_semantic;
+    }
+    bump_relationship_descriptor_version(original_descriptor, &mut updated_descriptor)?;
+    validate_relationship_descriptor(&updated_descriptor)?;
+
+    Ok(updated_descriptor)
+}
+
+pub fn update_holon_collection_descriptor(
+    original_descriptor: &HolonCollectionDescriptor,
+    new_description: Option<String>,
+    new_label: Option<String>,
+    min_items: Option<u32>,
+    max_items: Option<u32>,
+    unique_items: Option<bool>,
+    is_ordered: Option<bool>,
+) -> Result<HolonCollectionDescriptor, DescriptorsError> {
+    let mut updated_descriptor = original_descriptor.clone();
+    if let Some(description) = new_description {
+        updated_descriptor.header.description = description;
+    }
+    if let Some(label) = new_label {
+        updated_descriptor.header.label = label;
+    }
+    if let Some(min) = min_items {
+        updated_descriptor.min_items = min;
+    }
+    if let Some(max) = max_items {
+        updated_descriptor.max_items = max;
+    }
+    if let Some(unique_items) = unique_items {
+        updated_descriptor.unique_items = unique_items;
+    }
+    if let Some(is_ordered) = is_ordered {
+        updated_descriptor.is_ordered = is_ordered;
+    }
+    bump_holon_collection_descriptor_version(original_descriptor, &mut updated_descriptor)?;
+    validate_holon_collection_descriptor(&updated_descriptor)?;
 
     Ok(updated_descriptor)
 }
 
+/// Stages a new Holon as an instance of the given revision of a HolonDescriptor, checking that
+/// the property values conform to it. Like the other new_xxx functions, it does NOT commit it.
+pub fn new_holon(
+    original_descriptor_hash: ActionHash,
+    descriptor_revision_hash: ActionHash,
+    descriptor: &HolonDescriptor,
+    property_map: BTreeMap<String, BaseValue>,
+) -> Result<Holon, DescriptorsError> {
+    validate_holon_property_values(descriptor, &property_map)
+        .map_err(DescriptorsError::NonConformingValue)?;
+    Ok(Holon::new(
+        original_descriptor_hash,
+        descriptor_revision_hash,
+        descriptor.header.version.clone(),
+        property_map,
+    ))
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
@@ -0,0 +1,102 @@
+use descriptors_integrity::listing_validators::ALL_RELATIONSHIP_TYPES_PATH;
+use descriptors_integrity::*;
+use hdk::prelude::*;
+use shared_types_descriptor::relationship_descriptor::RelationshipDescriptor;
+
+use crate::descriptor_deletion::{delete_descriptor, DeleteDescriptorInput};
+use crate::descriptor_index::{index_descriptor, reindex_descriptor};
+use crate::descriptor_revisions::{ensure_latest_revision, get_latest_revision};
+use crate::helpers::get_relationship_descriptor_from_record;
+use crate::type_name_registry::{claim_type_name, link_type_name_claim};
+
+/// Creates a RelationshipDescriptor, failing if its type_name is already claimed. Claims another
+/// agent has made but this one cannot see yet are not detected (see `claim_type_name`).
+#[hdk_extern]
+pub fn create_relationship_descriptor(
+    relationship_descriptor: RelationshipDescriptor,
+) -> ExternResult<Record> {
+    claim_type_name(&relationship_descriptor.header.type_name)?;
+    let relationship_descriptor_hash = create_entry(&EntryTypes::RelationshipDescriptor(
+        relationship_descriptor.clone(),
+    ))?;
+    let record = get(relationship_descriptor_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not find the newly created RelationshipDescriptor"
+        ))),
+    )?;
+    let path = Path::from(ALL_RELATIONSHIP_TYPES_PATH);
+    create_link(
+        path.path_entry_hash()?,
+        relationship_descriptor_hash.clone(),
+        LinkTypes::AllRelationshipTypes,
+        (),
+    )?;
+    link_type_name_claim(
+        &relationship_descriptor.header.type_name,
+        relationship_descriptor_hash.clone(),
+    )?;
+    index_descriptor(&relationship_descriptor_hash, &relationship_descriptor.header)?;
+    Ok(record)
+}
+#[hdk_extern]
+pub fn get_relationship_descriptor(
+    original_relationship_descriptor_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+    get_latest_revision(
+        original_relationship_descriptor_hash,
+        LinkTypes::RelationshipDescriptorUpdates,
+    )
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateRelationshipDescriptorInput {
+    pub original_relationship_descriptor_hash: ActionHash,
+    pub previous_relationship_descriptor_hash: ActionHash,
+    pub updated_relationship_descriptor: RelationshipDescriptor,
+}
+#[hdk_extern]
+pub fn update_relationship_descriptor(
+    input: UpdateRelationshipDescriptorInput,
+) -> ExternResult<Record> {
+    ensure_latest_revision(
+        input.original_relationship_descriptor_hash.clone(),
+        &input.previous_relationship_descriptor_hash,
+        LinkTypes::RelationshipDescriptorUpdates,
+    )?;
+    let previous_record = get(
+        input.previous_relationship_descriptor_hash.clone(),
+        GetOptions::default(),
+    )?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+        "Could not find the previous revision of the RelationshipDescriptor"
+    ))))?;
+    let previous_relationship_descriptor =
+        get_relationship_descriptor_from_record(previous_record)?;
+    let updated_relationship_descriptor_hash = update_entry(
+        input.previous_relationship_descriptor_hash.clone(),
+        &input.updated_relationship_descriptor,
+    )?;
+    create_link(
+        input.original_relationship_descriptor_hash.clone(),
+        updated_relationship_descriptor_hash.clone(),
+        LinkTypes::RelationshipDescriptorUpdates,
+        (),
+    )?;
+    reindex_descriptor(
+        &input.original_relationship_descriptor_hash,
+        &previous_relationship_descriptor.header,
+        &input.updated_relationship_descriptor.header,
+    )?;
+    let record = get(updated_relationship_descriptor_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+            "Could not find the newly updated RelationshipDescriptor"
+        ))),
+    )?;
+    Ok(record)
+}
+/// Deletes a RelationshipDescriptor along with all of its revisions (see DeletionMode)
+#[hdk_extern]
+pub fn delete_relationship_descriptor(
+    input: DeleteDescriptorInput,
+) -> ExternResult<Vec<ActionHash>> {
+    delete_descriptor(input, LinkTypes::RelationshipDescriptorUpdates)
+}
@@ -0,0 +1,112 @@
+use descriptors_integrity::*;
+use hdk::prelude::*;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::schema_bundle::SchemaBundle;
+use shared_types_descriptor::type_descriptor::TypeDescriptor;
+use shared_types_descriptor::type_header::SemanticVersion;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::descriptor_batch::{create_descriptors_batch, get_descriptors_batch};
+use crate::holon_descriptor_queries::get_all_holon_types;
+use crate::type_name_registry::get_descriptor_by_type_name;
+use crate::value_descriptor_queries::get_all_value_descriptors;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportSchemaBundleInput {
+    pub name: String,
+    pub version: SemanticVersion,
+    // the type_names to export, each with the types it references (directly or not),
+    // or None to export every HolonDescriptor and ValueDescriptor in the registry
+    pub type_names: Option<Vec<String>>,
+}
+
+/// Exports the latest revisions of the selected descriptors as a SchemaBundle. References that
+/// identify their type by ActionHash alone are first given its type_name, and the export fails
+/// with DescriptorsError::UnresolvedReference if that ActionHash identifies no descriptor.
+#[hdk_extern]
+pub fn export_schema_bundle(input: ExportSchemaBundleInput) -> ExternResult<SchemaBundle> {
+    let descriptors = match input.type_names {
+        Some(type_names) => get_descriptors_with_references(type_names)?,
+        None => get_all_bundleable_descriptors()?,
+    };
+    Ok(SchemaBundle::new(input.name, input.version, descriptors))
+}
+
+/// Imports every descriptor of `bundle` as a single batch (see create_descriptors_batch), so
+/// either all of them are created or none are. References by type_name resolve to descriptors
+/// of the bundle, or else to those already in the registry. The import fails if any type_name
+/// of the bundle is already claimed.
+#[hdk_extern]
+pub fn import_schema_bundle(bundle: SchemaBundle) -> ExternResult<Vec<Record>> {
+    create_descriptors_batch(bundle.descriptors())
+}
+
+fn decode_descriptor(record: &Record) -> ExternResult<Option<TypeDescriptor>> {
+    Ok(entry_types_from_record(record)?.and_then(|e| e.into_type_descriptor()))
+}
+
+fn get_all_bundleable_descriptors() -> ExternResult<Vec<TypeDescriptor>> {
+    let original_hashes: Vec<ActionHash> = get_all_holon_types(())?
+        .into_iter()
+        .chain(get_all_value_descriptors(())?)
+        .map(|record| record.action_address().clone())
+        .collect();
+    let mut descriptors = Vec::new();
+    for record in get_descriptors_batch(original_hashes)?.into_iter().flatten() {
+        if let Some(mut descriptor) = decode_descriptor(&record)? {
+            name_references(&mut descriptor)?;
+            descriptors.push(descriptor);
+        }
+    }
+    Ok(descriptors)
+}
+
+// Gathers the descriptors of `type_names` and of every type they reference, so that the bundle
+// they make can be imported into a registry that has none of them
+fn get_descriptors_with_references(type_names: Vec<String>) -> ExternResult<Vec<TypeDescriptor>> {
+    let mut descriptors = Vec::new();
+    let mut visited: BTreeSet<String> = BTreeSet::new();
+    let mut pending = type_names;
+    while let Some(type_name) = pending.pop() {
+        if !visited.insert(type_name.clone()) {
+            continue;
+        }
+        let record = get_descriptor_by_type_name(type_name.clone())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest(DescriptorsError::UnknownTypeName(type_name.clone()).to_string())
+        ))?;
+        let mut descriptor = match decode_descriptor(&record)? {
+            Some(descriptor @ (TypeDescriptor::Holon(_) | TypeDescriptor::Value(_))) => descriptor,
+            _ => {
+                return Err(wasm_error!(WasmErrorInner::Guest(
+                    DescriptorsError::UnbundleableDescriptor(type_name).to_string()
+                )));
+            }
+        };
+        name_references(&mut descriptor)?;
+        pending.extend(descriptor.reference_names());
+        descriptors.push(descriptor);
+    }
+    Ok(descriptors)
+}
+
+// A bundle refers to types by type_name alone, so references without one are named after the
+// descriptor their ActionHash identifies
+fn name_references(descriptor: &mut TypeDescriptor) -> ExternResult<()> {
+    let mut names: BTreeMap<ActionHash, String> = BTreeMap::new();
+    for id in descriptor.unnamed_reference_ids() {
+        let referenced = match get(id.clone(), GetOptions::default())? {
+            Some(record) => decode_descriptor(&record)?,
+            None => None,
+        };
+        let referenced = referenced.ok_or(wasm_error!(WasmErrorInner::Guest(
+            DescriptorsError::UnresolvedReference(format!(
+                "{} reference to {id}",
+                descriptor.header().type_name
+            ))
+            .to_string()
+        )))?;
+        names.insert(id, referenced.header().type_name.clone());
+    }
+    descriptor.name_references(&mut |id| names.get(id).cloned());
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use descriptors_integrity::type_name_validators::type_name_path;
+use descriptors_integrity::*;
+use hdk::prelude::*;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::type_name_claim::TypeNameClaim;
+use std::collections::BTreeMap;
+
+use crate::holon_collection_descriptor_storage_fns::get_holon_collection_descriptor;
+use crate::holon_descriptor_storage_fns::get_holon_descriptor;
+use crate::relationship_descriptor_storage_fns::get_relationship_descriptor;
+use crate::value_descriptor_storage_fns::get_value_descriptor;
+
+/// The type_name registry maps each type_name to the original ActionHash of the one descriptor
+/// (of any kind) that owns it. HolonReference.name identifies types by name, so a name may only
+/// be claimed once across the whole registry. Deleting a descriptor releases its type_name, as
+/// claims whose descriptor has been deleted are ignored.
+
+/// Fails with DescriptorsError::DuplicateTypeName if some descriptor already claims `type_name`
+pub fn ensure_type_name_available(type_name: &str) -> ExternResult<()> {
+    if get_type_name_claim(type_name)?.is_some() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            DescriptorsError::DuplicateTypeName(type_name.to_string()).to_string()
+        )));
+    }
+    Ok(())
+}
+
+/// Commits the TypeNameClaim for `type_name`, once it is known to be available. The original
+/// Create of the descriptor claiming it must be the very next action on the source chain.
+///
+/// Availability is only checked against the claims this agent can see. Validation rejects an
+/// agent claiming a name it already holds, but not a name held by another agent whose claim has
+/// yet to reach this one, so on a partitioned network two agents may both claim a type_name. The
+/// earliest claim then owns it (see `get_type_name_claim`).
+pub fn claim_type_name(type_name: &str) -> ExternResult<ActionHash> {
+    ensure_type_name_available(type_name)?;
+    create_entry(&EntryTypes::TypeNameClaim(TypeNameClaim::new(type_name.to_string())))
+}
+
+pub fn link_type_name_claim(
+    type_name: &str,
+    original_hash: ActionHash,
+) -> ExternResult<ActionHash> {
+    create_link(
+        type_name_path(type_name).path_entry_hash()?,
+        original_hash,
+        LinkTypes::TypeNameClaims,
+        (),
+    )
+}
+
+/// Returns the original ActionHash of the descriptor that claims `type_name`. If concurrent
+/// creates on a partitioned network ever produce more than one claim, the descriptor created
+/// right after the earliest TypeNameClaim action wins.
+pub fn get_type_name_claim(type_name: &str) -> ExternResult<Option<ActionHash>> {
+    let claim_hash = hash_entry(&TypeNameClaim::new(type_name.to_string()))?;
+    let mut claim_actions = match get_details(claim_hash, GetOptions::default())? {
+        Some(Details::Entry(details)) => details.actions,
+        _ => {
+            return Ok(None);
+        }
+    };
+    claim_actions.sort_by(|action_a, action_b| {
+        action_a
+            .action()
+            .timestamp()
+            .cmp(&action_b.action().timestamp())
+            .then_with(|| action_a.as_hash().cmp(action_b.as_hash()))
+    });
+    let claimants = get_claimants(type_name)?;
+    Ok(claim_actions
+        .iter()
+        .find_map(|claim_action| claimants.get(claim_action.as_hash()).cloned()))
+}
+
+// Maps the claim action of every live descriptor linked from the path of `type_name` to the
+// original ActionHash of that descriptor, whose Create directly follows its claim
+fn get_claimants(type_name: &str) -> ExternResult<BTreeMap<ActionHash, ActionHash>> {
+    let links = get_links(
+        type_name_path(type_name).path_entry_hash()?,
+        LinkTypes::TypeNameClaims,
+        None,
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| GetInput::new(ActionHash::from(link.target).into(), GetOptions::default()))
+        .collect();
+    let details = HDK.with(|hdk| hdk.borrow().get_details(get_input))?;
+    Ok(details
+        .into_iter()
+        .filter_map(|details| match details {
+            Some(Details::Record(details)) if details.deletes.is_empty() => {
+                let record = details.record;
+                let claim_hash = record.action().prev_action()?.clone();
+                Some((claim_hash, record.action_address().clone()))
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+/// Resolves a type_name to the current (latest) record of the descriptor that claims it
+#[hdk_extern]
+pub fn get_descriptor_by_type_name(type_name: String) -> ExternResult<Option<Record>> {
+    let original_hash = match get_type_name_claim(&type_name)? {
+        Some(hash) => hash,
+        None => {
+            return Ok(None);
+        }
+    };
+    let original_record = match get(original_hash.clone(), GetOptions::default())? {
+        Some(record) => record,
+        None => {
+            return Ok(None);
+        }
+    };
+    match entry_types_from_record(&original_record)? {
+        Some(EntryTypes::HolonDescriptor(_)) => get_holon_descriptor(original_hash),
+        Some(EntryTypes::ValueDescriptor(_)) => get_value_descriptor(original_hash),
+        Some(EntryTypes::RelationshipDescriptor(_)) => get_relationship_descriptor(original_hash),
+        Some(EntryTypes::HolonCollectionDescriptor(_)) => {
+            get_holon_collection_descriptor(original_hash)
+        }
+        Some(EntryTypes::Holon(_)) | Some(EntryTypes::TypeNameClaim(_)) | None => Ok(None),
+    }
+}
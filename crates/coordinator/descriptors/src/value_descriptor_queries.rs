@@ -1,22 +1,25 @@
 use hdk::prelude::*;
 use descriptors_integrity::*;
+use descriptors_integrity::listing_validators::ALL_VALUE_DESCRIPTORS_PATH;
 
+use crate::descriptor_listing::{
+    get_listed_records, get_listing_cursors, list_descriptors, DescriptorPage,
+    ListDescriptorsInput, ListingOrder,
+};
+
+/// Returns every ValueDescriptor, in the order they were created. Use `list_value_descriptors`
+/// to page through larger registries.
 #[hdk_extern]
 pub fn get_all_value_descriptors(_: ()) -> ExternResult<Vec<Record>> {
-    let path = Path::from("all_value_descriptors");
-    let links = get_links(
-        path.path_entry_hash()?,
+    let cursors = get_listing_cursors(
+        ALL_VALUE_DESCRIPTORS_PATH,
         LinkTypes::AllValueDescriptors,
-        None,
+        ListingOrder::CreatedAt,
     )?;
-    let get_input: Vec<GetInput> = links
-        .into_iter()
-        .map(|link| GetInput::new(
-            ActionHash::from(link.target).into(),
-            GetOptions::default(),
-        ))
-        .collect();
-    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
-    let records: Vec<Record> = records.into_iter().filter_map(|r| r).collect();
-    Ok(records)
+    get_listed_records(&cursors)
+}
+
+#[hdk_extern]
+pub fn list_value_descriptors(input: ListDescriptorsInput) -> ExternResult<DescriptorPage> {
+    list_descriptors(ALL_VALUE_DESCRIPTORS_PATH, LinkTypes::AllValueDescriptors, input)
 }
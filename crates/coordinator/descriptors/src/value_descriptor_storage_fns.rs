@@ -1,11 +1,24 @@
 use hdk::prelude::*;
 use descriptors_integrity::*;
+use descriptors_integrity::listing_validators::ALL_VALUE_DESCRIPTORS_PATH;
 use shared_types_descriptor::value_descriptor::ValueDescriptor;
+use shared_types_descriptor::validation::shared_value_references;
 
+use crate::descriptor_deletion::{delete_descriptor, DeleteDescriptorInput};
+use crate::descriptor_dependents::{link_shared_references, relink_shared_references};
+use crate::descriptor_index::{index_descriptor, reindex_descriptor};
+use crate::descriptor_listing::add_to_listing;
+use crate::descriptor_revisions::{ensure_latest_revision, get_latest_revision};
+use crate::helpers::get_value_descriptor_from_record;
+use crate::type_name_registry::{claim_type_name, link_type_name_claim};
+
+/// Creates a ValueDescriptor, failing if its type_name is already claimed. Claims another agent
+/// has made but this one cannot see yet are not detected (see `claim_type_name`).
 #[hdk_extern]
 pub fn create_value_descriptor(
     value_descriptor: ValueDescriptor,
 ) -> ExternResult<Record> {
+    claim_type_name(&value_descriptor.header.type_name)?;
     let value_descriptor_hash = create_entry(
         &EntryTypes::ValueDescriptor(value_descriptor.clone()),
     )?;
@@ -15,13 +28,15 @@ pub fn create_value_descriptor(
                 WasmErrorInner::Guest(String::from("Could not find the newly created ValueDescriptor"))
             ),
         )?;
-    let path = Path::from("all_value_descriptors");
-    create_link(
-        path.path_entry_hash()?,
-        value_descriptor_hash.clone(),
+    add_to_listing(
+        ALL_VALUE_DESCRIPTORS_PATH,
         LinkTypes::AllValueDescriptors,
-        (),
+        &value_descriptor_hash,
+        &value_descriptor.header.type_name,
     )?;
+    link_type_name_claim(&value_descriptor.header.type_name, value_descriptor_hash.clone())?;
+    link_shared_references(&value_descriptor_hash, &shared_value_references(&value_descriptor))?;
+    index_descriptor(&value_descriptor_hash, &value_descriptor.header)?;
     Ok(record)
 }
 
@@ -29,19 +44,7 @@ pub fn create_value_descriptor(
 pub fn get_value_descriptor(
     original_value_descriptor_hash: ActionHash,
 ) -> ExternResult<Option<Record>> {
-    let links = get_links(
-        original_value_descriptor_hash.clone(),
-        LinkTypes::ValueDescriptorUpdates,
-        None,
-    )?;
-    let latest_link = links
-        .into_iter()
-        .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-    let latest_value_descriptor_hash = match latest_link {
-        Some(link) => ActionHash::from(link.target.clone()),
-        None => original_value_descriptor_hash.clone(),
-    };
-    get(latest_value_descriptor_hash, GetOptions::default())
+    get_latest_revision(original_value_descriptor_hash, LinkTypes::ValueDescriptorUpdates)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -55,6 +58,19 @@ pub struct UpdateValueDescriptorInput {
 pub fn update_value_descriptor(
     input: UpdateValueDescriptorInput,
 ) -> ExternResult<Record> {
+    ensure_latest_revision(
+        input.original_value_descriptor_hash.clone(),
+        &input.previous_value_descriptor_hash,
+        LinkTypes::ValueDescriptorUpdates,
+    )?;
+    let previous_record = get(
+        input.previous_value_descriptor_hash.clone(),
+        GetOptions::default(),
+    )?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+        "Could not find the previous revision of the ValueDescriptor"
+    ))))?;
+    let previous_value_descriptor = get_value_descriptor_from_record(previous_record)?;
     let updated_value_descriptor_hash = update_entry(
         input.previous_value_descriptor_hash.clone(),
         &input.updated_value_descriptor,
@@ -65,6 +81,16 @@ pub fn update_value_descriptor(
         LinkTypes::ValueDescriptorUpdates,
         (),
     )?;
+    relink_shared_references(
+        &input.original_value_descriptor_hash,
+        &shared_value_references(&previous_value_descriptor),
+        &shared_value_references(&input.updated_value_descriptor),
+    )?;
+    reindex_descriptor(
+        &input.original_value_descriptor_hash,
+        &previous_value_descriptor.header,
+        &input.updated_value_descriptor.header,
+    )?;
     let record = get(updated_value_descriptor_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
@@ -74,9 +100,8 @@ pub fn update_value_descriptor(
     Ok(record)
 }
 
+/// Deletes a ValueDescriptor along with all of its revisions (see DeletionMode)
 #[hdk_extern]
-pub fn delete_value_descriptor(
-    original_value_descriptor_hash: ActionHash,
-) -> ExternResult<ActionHash> {
-    delete_entry(original_value_descriptor_hash)
+pub fn delete_value_descriptor(input: DeleteDescriptorInput) -> ExternResult<Vec<ActionHash>> {
+    delete_descriptor(input, LinkTypes::ValueDescriptorUpdates)
 }
@@ -0,0 +1,113 @@
+//! Descriptor Batch Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+use std::collections::BTreeSet;
+
+use descriptors::helpers::{
+    get_composite_descriptor_map, get_holon_reference_from_sharing,
+    get_value_descriptor_from_record,
+};
+use rstest::*;
+use shared_test::fixture_helpers::{composite_sharing, string_descriptor};
+use shared_types_descriptor::holon_descriptor::HolonReference;
+use shared_types_descriptor::type_descriptor::TypeDescriptor;
+use shared_types_descriptor::value_descriptor::ValueDescriptor;
+
+fn shared_reference(descriptor: &ValueDescriptor) -> HolonReference {
+    let property_map = get_composite_descriptor_map(&descriptor.details);
+    get_holon_reference_from_sharing(&property_map.properties["shared"].sharing)
+}
+
+/// These tests check which references of a descriptor are left for a batch to resolve.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test descriptor_batch_tests -- --show-output
+#[rstest]
+#[case::by_name_only(None, vec!["street_String_Type"])]
+#[case::already_resolved(Some(ActionHash::from_raw_36(vec![0; 36])), vec![])]
+fn rstest_unresolved_reference_names(
+    #[case] action_hash: Option<ActionHash>,
+    #[case] expected: Vec<&str>,
+) {
+    let descriptor = composite_sharing("address", &string_descriptor("street"), action_hash);
+    let expected: BTreeSet<String> = expected.into_iter().map(String::from).collect();
+    assert_eq!(expected, TypeDescriptor::Value(descriptor).unresolved_reference_names());
+}
+
+/// This test verifies that a batch of descriptors is created in a single call, with references
+/// between them resolved by type_name, and that a batch of them can be fetched at once.
+///
+/// Test Outline:
+/// 1. Create a batch holding an `address` composite that shares `street` by type_name only,
+///    listed ahead of `street` itself, and verify the records come back in the order of the
+///    batch with the reference resolved to the ActionHash of `street`.
+/// 2. Fetch both with `get_descriptors_batch`, along with an unknown ActionHash.
+/// 3. Verify that batches with a duplicate type_name or a reference cycle are rejected without
+///    creating any of their descriptors.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_descriptor_batch() {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. Create a batch that references within itself
+    let street = string_descriptor("street");
+    let address = composite_sharing("address", &street, None);
+    let batch = vec![
+        TypeDescriptor::Value(address.clone()),
+        TypeDescriptor::Value(street.clone()),
+    ];
+    let records: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "create_descriptors_batch", batch)
+        .await;
+    assert_eq!(2, records.len());
+    let created_address = get_value_descriptor_from_record(records[0].clone()).unwrap();
+    let created_street = get_value_descriptor_from_record(records[1].clone()).unwrap();
+    assert_eq!(street, created_street);
+    assert_eq!(address.header, created_address.header);
+    let reference = shared_reference(&created_address);
+    assert_eq!(Some(records[1].action_address().clone()), reference.id);
+    assert_eq!(Some(street.header.type_name.clone()), reference.name);
+    println!("Success! The batch resolved `address` to the `street` it created");
+
+    // 2. Fetch the batch back
+    let unknown_hash = ActionHash::from_raw_36(vec![0; 36]);
+    let original_hashes = vec![
+        records[1].action_address().clone(),
+        unknown_hash,
+        records[0].action_address().clone(),
+    ];
+    let fetched: Vec<Option<Record>> = conductor
+        .call(&cell.zome("descriptors"), "get_descriptors_batch", original_hashes)
+        .await;
+    assert_eq!(vec![Some(records[1].clone()), None, Some(records[0].clone())], fetched);
+    println!("Success! get_descriptors_batch fetched the batch in the order asked");
+
+    // 3. Rejected batches create nothing
+    let duplicate_batch = vec![
+        TypeDescriptor::Value(string_descriptor("city")),
+        TypeDescriptor::Value(string_descriptor("city")),
+    ];
+    let result: Result<Vec<Record>, _> = conductor
+        .call_fallible(&cell.zome("descriptors"), "create_descriptors_batch", duplicate_batch)
+        .await;
+    assert!(result.is_err());
+
+    let mut left = composite_sharing("left", &string_descriptor("unused"), None);
+    let right = composite_sharing("right", &left, None);
+    left = composite_sharing("left", &right, None);
+    let cyclic_batch = vec![TypeDescriptor::Value(left), TypeDescriptor::Value(right)];
+    let result: Result<Vec<Record>, _> = conductor
+        .call_fallible(&cell.zome("descriptors"), "create_descriptors_batch", cyclic_batch)
+        .await;
+    assert!(result.is_err());
+
+    let all: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_all_value_descriptors", ())
+        .await;
+    assert_eq!(2, all.len());
+    println!("Success! Duplicate and cyclic batches were rejected");
+}
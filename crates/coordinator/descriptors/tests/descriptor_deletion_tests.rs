@@ -0,0 +1,194 @@
+//! Descriptor Deletion Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+use holochain::test_utils::consistency_10s;
+
+use descriptors::descriptor_deletion::{DeleteDescriptorInput, DeletionMode};
+use descriptors::mutators::new_holon_descriptor;
+use descriptors::property_map_builder::upsert_property_descriptor;
+use rstest::*;
+use shared_test::fixture_helpers::{
+    composite_sharing, derive_label, derive_type_description, derive_type_name, shared_usage,
+    string_descriptor,
+};
+use shared_types_descriptor::type_header::BaseType;
+
+fn delete_input(original_hash: &ActionHash, mode: DeletionMode) -> DeleteDescriptorInput {
+    DeleteDescriptorInput {
+        original_descriptor_hash: original_hash.clone(),
+        mode,
+    }
+}
+
+/// This test verifies that descriptors in use are only deleted when the DeletionMode says what to
+/// do about their dependents.
+///
+/// Test Outline:
+/// 1. Create a shared `handle` string, a `contact` composite that shares it, and a `member`
+///    HolonDescriptor that shares `contact`.
+/// 2. Attempt to delete `handle` with the Restrict mode, and `member` through
+///    `delete_value_descriptor`, and verify both are refused.
+/// 3. Delete `handle` with the Cascade mode, and verify all three descriptors are deleted and
+///    no longer listed by `get_all_value_descriptors` or `get_all_holon_types`.
+/// 4. Create a shared `nickname` string and a composite that shares it, delete `nickname` with
+///    the Force mode and verify only `nickname` is deleted.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test descriptor_deletion_tests -- --show-output
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_descriptor_deletion() {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. member -> contact -> handle
+    let handle = string_descriptor("handle");
+    let handle_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", handle.clone())
+        .await;
+    let handle_hash = handle_record.action_address().clone();
+    let contact_reference = Some(handle_record.action_address().clone());
+    let contact = composite_sharing("contact", &handle, contact_reference);
+    let contact_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", contact.clone())
+        .await;
+    let type_name = derive_type_name("member", BaseType::Holon, "");
+    let mut member = new_holon_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+    )
+    .unwrap();
+    let contact_usage = shared_usage(&contact, &contact_record);
+    upsert_property_descriptor(&mut member.property_map, "contact".to_string(), &contact_usage);
+    let member_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", member)
+        .await;
+
+    // 2. Restrict refuses to delete a descriptor in use
+    let result: Result<Vec<ActionHash>, _> = conductor
+        .call_fallible(
+            &cell.zome("descriptors"),
+            "delete_value_descriptor",
+            delete_input(&handle_hash, DeletionMode::Restrict),
+        )
+        .await;
+    assert!(result.is_err());
+    let fetched: Option<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_value_descriptor", handle_hash.clone())
+        .await;
+    assert!(fetched.is_some());
+    println!("Success! Deleting a descriptor in use was refused");
+
+    // a descriptor of one kind cannot be deleted through the extern of another
+    let result: Result<Vec<ActionHash>, _> = conductor
+        .call_fallible(
+            &cell.zome("descriptors"),
+            "delete_value_descriptor",
+            delete_input(member_record.action_address(), DeletionMode::Cascade),
+        )
+        .await;
+    let error = format!("{:?}", result.unwrap_err());
+    assert!(error.contains("is not a descriptor of the kind being deleted"), "{error}");
+    println!("Success! Deleting a HolonDescriptor as a ValueDescriptor was refused");
+
+    // 3. Cascade deletes the dependents first
+    let deleted: Vec<ActionHash> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "delete_value_descriptor",
+            delete_input(&handle_hash, DeletionMode::Cascade),
+        )
+        .await;
+    let expected = vec![
+        member_record.action_address().clone(),
+        contact_record.action_address().clone(),
+        handle_hash.clone(),
+    ];
+    assert_eq!(expected, deleted);
+    let all_value_descriptors: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_all_value_descriptors", ())
+        .await;
+    assert!(all_value_descriptors.is_empty());
+    let all_holon_types: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_all_holon_types", ())
+        .await;
+    assert!(all_holon_types.is_empty());
+    println!("Success! Cascade deleted the descriptor and its dependents");
+
+    // 4. Force deletes the descriptor alone
+    let nickname = string_descriptor("nickname");
+    let nickname_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", nickname.clone())
+        .await;
+    let nickname_hash = nickname_record.action_address().clone();
+    let profile_reference = Some(nickname_record.action_address().clone());
+    let profile = composite_sharing("profile", &nickname, profile_reference);
+    let profile_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", profile)
+        .await;
+    let deleted: Vec<ActionHash> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "delete_value_descriptor",
+            delete_input(&nickname_hash, DeletionMode::Force),
+        )
+        .await;
+    assert_eq!(vec![nickname_hash], deleted);
+    let all_value_descriptors: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_all_value_descriptors", ())
+        .await;
+    assert_eq!(vec![profile_record], all_value_descriptors);
+    println!("Success! Force deleted only the descriptor");
+}
+
+/// This test verifies that a descriptor can be deleted by an agent other than the one who
+/// created it, along with the links that list it and record its references.
+///
+/// Test Outline:
+/// 1. As one agent, create a shared `alias` string and a `badge` composite that shares it.
+/// 2. As another agent, delete `alias` with the Cascade mode.
+/// 3. Verify that, for both agents, neither descriptor is listed by `get_all_value_descriptors`.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_descriptor_deleted_by_another_agent() {
+    let (conductors, cells) = shared_test::setup_conductors(2).await;
+    conductors.exchange_peer_info().await;
+
+    // 1. The author creates badge -> alias
+    let alias = string_descriptor("alias");
+    let alias_record: Record = conductors[0]
+        .call(&cells[0].zome("descriptors"), "create_value_descriptor", alias.clone())
+        .await;
+    let alias_hash = alias_record.action_address().clone();
+    let badge_reference = Some(alias_record.action_address().clone());
+    let badge = composite_sharing("badge", &alias, badge_reference);
+    let badge_record: Record = conductors[0]
+        .call(&cells[0].zome("descriptors"), "create_value_descriptor", badge)
+        .await;
+    consistency_10s([&cells[0], &cells[1]]).await;
+
+    // 2. Another agent deletes them
+    let deleted: Vec<ActionHash> = conductors[1]
+        .call(
+            &cells[1].zome("descriptors"),
+            "delete_value_descriptor",
+            delete_input(&alias_hash, DeletionMode::Cascade),
+        )
+        .await;
+    assert_eq!(vec![badge_record.action_address().clone(), alias_hash], deleted);
+    consistency_10s([&cells[0], &cells[1]]).await;
+
+    // 3. Neither agent lists them any more
+    for (conductor, cell) in conductors.iter().zip(cells.iter()) {
+        let all_value_descriptors: Vec<Record> = conductor
+            .call(&cell.zome("descriptors"), "get_all_value_descriptors", ())
+            .await;
+        assert!(all_value_descriptors.is_empty());
+    }
+    println!("Success! Another agent deleted the descriptors and their links");
+}
@@ -0,0 +1,204 @@
+//! Descriptor Dependents Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+use holochain::test_utils::consistency_10s;
+use std::collections::BTreeMap;
+
+use descriptors::descriptor_dependents::DescriptorDependent;
+use descriptors::mutators::{
+    bump_value_descriptor_version, new_composite_descriptor, new_holon_descriptor,
+    new_string_descriptor,
+};
+use descriptors::property_map_builder::{remove_property_descriptor, upsert_property_descriptor};
+use descriptors::value_descriptor_storage_fns::UpdateValueDescriptorInput;
+use rstest::*;
+use shared_test::fixture_helpers::{
+    derive_label, derive_type_description, derive_type_name, shared_usage,
+};
+use shared_types_descriptor::type_header::BaseType;
+use shared_types_descriptor::value_descriptor::{PropertyDescriptorMap, ValueDescriptorDetails};
+
+/// This test verifies that UsedBy links track the descriptors that use a shared descriptor.
+///
+/// Test Outline:
+/// 1. Create a shared `handle` string, a `contact` composite that shares it, and a `member`
+///    HolonDescriptor that shares `contact`.
+/// 2. Verify `get_descriptor_dependents` lists `contact` as a direct dependent of `handle`, and
+///    `member` as a transitive one, each with the property path of the reference.
+/// 3. Update `contact` to drop its `handle` property and verify `handle` has no dependents.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test descriptor_dependents_tests -- --show-output
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_descriptor_dependents() {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. member -> contact -> handle
+    let type_name = derive_type_name("handle", BaseType::String, "");
+    let handle = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        3,
+        16,
+    )
+    .unwrap();
+    let handle_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", handle.clone())
+        .await;
+    let handle_hash = handle_record.action_address().clone();
+
+    let mut contact_properties = PropertyDescriptorMap::new(BTreeMap::new());
+    let handle_usage = shared_usage(&handle, &handle_record);
+    upsert_property_descriptor(&mut contact_properties, "handle".to_string(), &handle_usage);
+    let type_name = derive_type_name("contact", BaseType::Composite, "");
+    let contact = new_composite_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        contact_properties,
+    )
+    .unwrap();
+    let contact_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", contact.clone())
+        .await;
+    let contact_hash = contact_record.action_address().clone();
+
+    let type_name = derive_type_name("member", BaseType::Holon, "");
+    let mut member = new_holon_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+    )
+    .unwrap();
+    let contact_usage = shared_usage(&contact, &contact_record);
+    upsert_property_descriptor(&mut member.property_map, "contact".to_string(), &contact_usage);
+    let member_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", member.clone())
+        .await;
+
+    // 2. Direct and transitive dependents
+    let handle_path = format!("{}.handle", contact.header.type_name);
+    let contact_path = format!("{}.contact", member.header.type_name);
+    let expected = vec![
+        DescriptorDependent {
+            dependent_hash: contact_hash.clone(),
+            property_path: vec![handle_path.clone()],
+        },
+        DescriptorDependent {
+            dependent_hash: member_record.action_address().clone(),
+            property_path: vec![contact_path, handle_path],
+        },
+    ];
+    let dependents: Vec<DescriptorDependent> = conductor
+        .call(&cell.zome("descriptors"), "get_descriptor_dependents", handle_hash.clone())
+        .await;
+    assert_eq!(expected, dependents);
+    assert!(dependents[0].is_direct());
+    assert!(!dependents[1].is_direct());
+    println!("Success! Direct and transitive dependents were found");
+
+    // 3. Dropping the reference removes the dependency
+    let mut updated_contact = contact.clone();
+    if let ValueDescriptorDetails::Composite(ref mut details) = updated_contact.details {
+        remove_property_descriptor(&mut details.property_map, "handle".to_string());
+    }
+    bump_value_descriptor_version(&contact, &mut updated_contact).unwrap();
+    let update_input = UpdateValueDescriptorInput {
+        original_value_descriptor_hash: contact_hash.clone(),
+        previous_value_descriptor_hash: contact_hash,
+        updated_value_descriptor: updated_contact,
+    };
+    let _updated_record: Record = conductor
+        .call(&cell.zome("descriptors"), "update_value_descriptor", update_input)
+        .await;
+    let dependents: Vec<DescriptorDependent> = conductor
+        .call(&cell.zome("descriptors"), "get_descriptor_dependents", handle_hash)
+        .await;
+    assert!(dependents.is_empty());
+    println!("Success! Dropped reference is no longer listed as a dependency");
+}
+
+/// This test verifies that the UsedBy links of a descriptor are kept up to date when it is
+/// revised by an agent other than the one who created it.
+///
+/// Test Outline:
+/// 1. As one agent, create a shared `nickname` string and a `profile` composite that shares it.
+/// 2. As another agent, update `profile` to drop its `nickname` property.
+/// 3. Verify that, for both agents, `nickname` no longer has any dependents.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_dependents_updated_by_another_agent() {
+    let (conductors, cells) = shared_test::setup_conductors(2).await;
+    conductors.exchange_peer_info().await;
+
+    // 1. The author creates profile -> nickname
+    let type_name = derive_type_name("nickname", BaseType::String, "");
+    let nickname = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        1,
+        32,
+    )
+    .unwrap();
+    let nickname_record: Record = conductors[0]
+        .call(&cells[0].zome("descriptors"), "create_value_descriptor", nickname.clone())
+        .await;
+
+    let mut profile_properties = PropertyDescriptorMap::new(BTreeMap::new());
+    let nickname_usage = shared_usage(&nickname, &nickname_record);
+    upsert_property_descriptor(&mut profile_properties, "nickname".to_string(), &nickname_usage);
+    let type_name = derive_type_name("profile", BaseType::Composite, "");
+    let profile = new_composite_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        profile_properties,
+    )
+    .unwrap();
+    let profile_record: Record = conductors[0]
+        .call(&cells[0].zome("descriptors"), "create_value_descriptor", profile.clone())
+        .await;
+    let profile_hash = profile_record.action_address().clone();
+    consistency_10s([&cells[0], &cells[1]]).await;
+
+    // 2. Another agent drops the reference
+    let mut updated_profile = profile.clone();
+    if let ValueDescriptorDetails::Composite(ref mut details) = updated_profile.details {
+        remove_property_descriptor(&mut details.property_map, "nickname".to_string());
+    }
+    bump_value_descriptor_version(&profile, &mut updated_profile).unwrap();
+    let update_input = UpdateValueDescriptorInput {
+        original_value_descriptor_hash: profile_hash.clone(),
+        previous_value_descriptor_hash: profile_hash,
+        updated_value_descriptor: updated_profile,
+    };
+    let _updated_record: Record = conductors[1]
+        .call(&cells[1].zome("descriptors"), "update_value_descriptor", update_input)
+        .await;
+    consistency_10s([&cells[0], &cells[1]]).await;
+
+    // 3. Neither agent sees the dependency any more
+    for (conductor, cell) in conductors.iter().zip(cells.iter()) {
+        let dependents: Vec<DescriptorDependent> = conductor
+            .call(
+                &cell.zome("descriptors"),
+                "get_descriptor_dependents",
+                nickname_record.action_address().clone(),
+            )
+            .await;
+        assert!(dependents.is_empty());
+    }
+    println!("Success! Reference dropped by another agent is no longer listed as a dependency");
+}
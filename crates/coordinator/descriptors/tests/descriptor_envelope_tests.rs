@@ -0,0 +1,149 @@
+//! Descriptor Envelope Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+
+use descriptors::descriptor_deletion::{DeleteDescriptorInput, DeletionMode};
+use descriptors::holon_descriptor_storage_fns::UpdateHolonDescriptorInput;
+use descriptors::mutators::{
+    bump_holon_descriptor_version, bump_value_descriptor_version, new_holon_descriptor,
+    new_integer_descriptor,
+};
+use descriptors::value_descriptor_storage_fns::UpdateValueDescriptorInput;
+use rstest::*;
+use shared_test::fixture_helpers::{derive_label, derive_type_description, derive_type_name};
+use shared_types_descriptor::descriptor_envelope::DescriptorEnvelope;
+use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+use shared_types_descriptor::type_header::{BaseType, SemanticVersion};
+use shared_types_descriptor::value_descriptor::ValueDescriptor;
+
+/// This test verifies that the typed query externs return the latest revision of a descriptor
+/// in a DescriptorEnvelope, along with its hashes and provenance.
+///
+/// Test Outline:
+/// 1. Create a `score` ValueDescriptor and a `player` HolonDescriptor, and verify their
+///    envelopes describe the original revisions.
+/// 2. Update `score` and verify its envelope carries the new revision and version, while
+///    keeping the original hash, author and creation time.
+/// 3. Verify the get_all_xxx_envelopes externs envelope every descriptor of their kind.
+/// 4. Delete `score` and verify it no longer has an envelope.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test descriptor_envelope_tests -- --show-output
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_descriptor_envelopes() {
+    let (conductor, agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. Envelopes of the original revisions
+    let type_name = derive_type_name("score", BaseType::Integer, "");
+    let score = new_integer_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        0,
+        100,
+    )
+    .unwrap();
+    let score_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", score.clone())
+        .await;
+    let score_hash = score_record.action_address().clone();
+    let envelope: Option<DescriptorEnvelope<ValueDescriptor>> = conductor
+        .call(&cell.zome("descriptors"), "get_value_descriptor_envelope", score_hash.clone())
+        .await;
+    let envelope = envelope.unwrap();
+    assert_eq!(score, envelope.descriptor);
+    assert_eq!(score_hash, envelope.original_hash);
+    assert_eq!(score_hash, envelope.revision_hash);
+    assert_eq!(agent, envelope.author);
+    assert_eq!(agent, envelope.revision_author);
+    assert_eq!(score_record.action().timestamp(), envelope.created_at);
+    assert_eq!(envelope.created_at, envelope.updated_at);
+    assert_eq!(SemanticVersion::default(), envelope.version);
+
+    let type_name = derive_type_name("player", BaseType::Holon, "");
+    let player = new_holon_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+    )
+    .unwrap();
+    let player_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", player.clone())
+        .await;
+    let player_hash = player_record.action_address().clone();
+    let player_envelope: Option<DescriptorEnvelope<HolonDescriptor>> = conductor
+        .call(&cell.zome("descriptors"), "get_holon_descriptor_envelope", player_hash.clone())
+        .await;
+    let player_envelope = player_envelope.unwrap();
+    assert_eq!(player, player_envelope.descriptor);
+    assert_eq!(player_hash, player_envelope.original_hash);
+    println!("Success! Envelopes describe the original revisions");
+
+    // 2. Envelope of an updated descriptor
+    let mut updated_score = score.clone();
+    updated_score.header.description = "points scored in a round".to_string();
+    bump_value_descriptor_version(&score, &mut updated_score).unwrap();
+    let update_input = UpdateValueDescriptorInput {
+        original_value_descriptor_hash: score_hash.clone(),
+        previous_value_descriptor_hash: score_hash.clone(),
+        updated_value_descriptor: updated_score.clone(),
+    };
+    let updated_record: Record = conductor
+        .call(&cell.zome("descriptors"), "update_value_descriptor", update_input)
+        .await;
+    let updated_envelope: Option<DescriptorEnvelope<ValueDescriptor>> = conductor
+        .call(&cell.zome("descriptors"), "get_value_descriptor_envelope", score_hash.clone())
+        .await;
+    let updated_envelope = updated_envelope.unwrap();
+    assert_eq!(updated_score, updated_envelope.descriptor);
+    assert_eq!(score_hash, updated_envelope.original_hash);
+    assert_eq!(updated_record.action_address(), &updated_envelope.revision_hash);
+    assert_eq!(envelope.created_at, updated_envelope.created_at);
+    assert_eq!(updated_record.action().timestamp(), updated_envelope.updated_at);
+    assert_eq!(updated_score.header.version, updated_envelope.version);
+    println!("Success! The envelope follows the latest revision");
+
+    // 3. Envelopes of every descriptor of a kind
+    let all_values: Vec<DescriptorEnvelope<ValueDescriptor>> = conductor
+        .call(&cell.zome("descriptors"), "get_all_value_descriptor_envelopes", ())
+        .await;
+    assert_eq!(vec![updated_envelope], all_values);
+    let mut updated_player = player.clone();
+    updated_player.header.label = "a player in the league".to_string();
+    bump_holon_descriptor_version(&player, &mut updated_player).unwrap();
+    let update_input = UpdateHolonDescriptorInput {
+        original_holon_descriptor_hash: player_hash.clone(),
+        previous_holon_descriptor_hash: player_hash,
+        updated_holon_descriptor: updated_player.clone(),
+    };
+    let _updated_record: Record = conductor
+        .call(&cell.zome("descriptors"), "update_holon_descriptor", update_input)
+        .await;
+    let all_holons: Vec<DescriptorEnvelope<HolonDescriptor>> = conductor
+        .call(&cell.zome("descriptors"), "get_all_holon_type_envelopes", ())
+        .await;
+    assert_eq!(1, all_holons.len());
+    assert_eq!(updated_player, all_holons[0].descriptor);
+    println!("Success! get_all_xxx_envelopes envelopes the latest revisions");
+
+    // 4. Deleted descriptors have no envelope
+    let delete_input = DeleteDescriptorInput {
+        original_descriptor_hash: score_hash.clone(),
+        mode: DeletionMode::Restrict,
+    };
+    let _deleted_hashes: Vec<ActionHash> = conductor
+        .call(&cell.zome("descriptors"), "delete_value_descriptor", delete_input)
+        .await;
+    let deleted_envelope: Option<DescriptorEnvelope<ValueDescriptor>> = conductor
+        .call(&cell.zome("descriptors"), "get_value_descriptor_envelope", score_hash)
+        .await;
+    assert!(deleted_envelope.is_none());
+    println!("Success! Deleted descriptors have no envelope");
+}
@@ -0,0 +1,174 @@
+//! Descriptor History Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+
+use descriptors::descriptor_revisions::{
+    DescriptorHeads, DescriptorRevision, GetDescriptorAtVersionInput,
+};
+use descriptors::helpers::get_holon_descriptor_from_record;
+use descriptors::holon_descriptor_storage_fns::UpdateHolonDescriptorInput;
+use rstest::*;
+use shared_test::holon_descriptor_fixtures::*;
+use shared_test::test_data_types::HolonDescriptorTestCase;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+
+/// This test exercises the revision history of a HolonDescriptor.
+///
+/// Test Outline:
+/// 1. Create the original descriptor, then apply each of the updates in turn.
+/// 2. Perform `get_holon_descriptor_history` and verify that it lists the original followed by
+///    every update, in version order, each chained to its predecessor.
+/// 3. For each expected descriptor, perform `get_descriptor_at_version` and verify it returns
+///    exactly that revision.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test descriptor_history_tests -- --show-output
+#[rstest]
+#[case::remove_properties_from_holon_descriptor(remove_properties())]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_holon_descriptor_history(
+    #[case] input: Result<HolonDescriptorTestCase, DescriptorsError>,
+) {
+    let (conductor, agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    let test_case = input.unwrap();
+    let mut expected_descriptors: Vec<HolonDescriptor> = vec![test_case.original.clone()];
+    expected_descriptors.extend(test_case.updates.clone());
+
+    let created_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "create_holon_descriptor",
+            test_case.original.clone(),
+        )
+        .await;
+    let original_hash: ActionHash = created_record.action_address().clone();
+
+    let mut previous_hash = original_hash.clone();
+    for descriptor in test_case.updates {
+        let update_input = UpdateHolonDescriptorInput {
+            original_holon_descriptor_hash: original_hash.clone(),
+            previous_holon_descriptor_hash: previous_hash.clone(),
+            updated_holon_descriptor: descriptor,
+        };
+        let updated_record: Record = conductor
+            .call(
+                &cell.zome("descriptors"),
+                "update_holon_descriptor",
+                update_input,
+            )
+            .await;
+        previous_hash = updated_record.action_address().clone();
+    }
+
+    let history: Vec<DescriptorRevision> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_holon_descriptor_history",
+            original_hash.clone(),
+        )
+        .await;
+    println!("history: {:#?}", history);
+    assert_eq!(expected_descriptors.len(), history.len());
+    assert_eq!(original_hash, history[0].action_hash);
+    assert!(history[0].previous_action_hash.is_none());
+    for (i, revision) in history.iter().enumerate() {
+        assert_eq!(expected_descriptors[i].header.version, revision.version);
+        assert_eq!(agent, revision.author);
+        if i > 0 {
+            assert_eq!(
+                Some(history[i - 1].action_hash.clone()),
+                revision.previous_action_hash
+            );
+        }
+    }
+    println!("...Success! History lists every revision in version order. \n");
+
+    for expected in expected_descriptors {
+        let fetched_record: Option<Record> = conductor
+            .call(
+                &cell.zome("descriptors"),
+                "get_descriptor_at_version",
+                GetDescriptorAtVersionInput {
+                    original_descriptor_hash: original_hash.clone(),
+                    version: expected.header.version.clone(),
+                },
+            )
+            .await;
+        let fetched = get_holon_descriptor_from_record(fetched_record.unwrap()).unwrap();
+        assert_eq!(expected, fetched);
+    }
+    println!("...Success! Each version can be fetched by its SemanticVersion. \n");
+}
+
+/// This test verifies the optimistic concurrency check on updates: once a revision has been
+/// updated it is stale, so a second update derived from it is rejected instead of forking the
+/// revision tree, and the descriptor keeps a single head.
+#[rstest]
+#[case::remove_properties_from_holon_descriptor(remove_properties())]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_holon_descriptor_stale_update(
+    #[case] input: Result<HolonDescriptorTestCase, DescriptorsError>,
+) {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    let test_case = input.unwrap();
+    assert!(test_case.updates.len() >= 2);
+    let created_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "create_holon_descriptor",
+            test_case.original.clone(),
+        )
+        .await;
+    let original_hash: ActionHash = created_record.action_address().clone();
+
+    let update_input = UpdateHolonDescriptorInput {
+        original_holon_descriptor_hash: original_hash.clone(),
+        previous_holon_descriptor_hash: original_hash.clone(),
+        updated_holon_descriptor: test_case.updates[0].clone(),
+    };
+    let updated_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "update_holon_descriptor",
+            update_input,
+        )
+        .await;
+
+    // The original is no longer the latest revision, so deriving another update from it is stale
+    let stale_input = UpdateHolonDescriptorInput {
+        original_holon_descriptor_hash: original_hash.clone(),
+        previous_holon_descriptor_hash: original_hash.clone(),
+        updated_holon_descriptor: test_case.updates[1].clone(),
+    };
+    let stale_result: Result<Record, _> = conductor
+        .call_fallible(
+            &cell.zome("descriptors"),
+            "update_holon_descriptor",
+            stale_input,
+        )
+        .await;
+    assert!(stale_result.is_err());
+    println!("...Success! Stale update was rejected: {:?}", stale_result.err());
+
+    let heads: DescriptorHeads = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_descriptor_heads",
+            original_hash.clone(),
+        )
+        .await;
+    println!("heads: {:#?}", heads);
+    assert!(!heads.is_forked());
+    assert!(heads.forks.is_empty());
+    assert_eq!(1, heads.heads.len());
+    assert_eq!(*updated_record.action_address(), heads.heads[0].action_hash);
+    println!("...Success! Descriptor still has a single head. \n");
+}
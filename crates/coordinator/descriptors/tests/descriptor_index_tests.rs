@@ -0,0 +1,269 @@
+//! Descriptor Index Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+use holochain::test_utils::consistency_10s;
+use std::collections::BTreeSet;
+
+use descriptors::descriptor_deletion::{DeleteDescriptorInput, DeletionMode};
+use descriptors::mutators::{
+    bump_value_descriptor_version, new_holon_descriptor, new_integer_descriptor,
+    new_string_descriptor,
+};
+use descriptors::value_descriptor_storage_fns::UpdateValueDescriptorInput;
+use rstest::*;
+use shared_test::fixture_helpers::derive_type_name;
+use shared_types_descriptor::type_descriptor::TypeDescriptor;
+use shared_types_descriptor::type_header::{tokenize, BaseType};
+use shared_types_descriptor::value_descriptor::ValueDescriptor;
+
+fn tokens(words: &[&str]) -> BTreeSet<String> {
+    words.iter().map(|word| word.to_string()).collect()
+}
+
+/// These tests check how labels, descriptions and queries are split into search tokens.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test descriptor_index_tests -- --show-output
+#[rstest]
+#[case::words("Street Address", tokens(&["address", "street"]))]
+#[case::punctuation("Street-Address (line 1)", tokens(&["address", "line", "street"]))]
+#[case::duplicates("address of an Address", tokens(&["address", "an", "of"]))]
+#[case::type_name("street_String_Type", tokens(&["street", "string", "type"]))]
+#[case::too_short("a 1 b", tokens(&[]))]
+fn rstest_tokenize(#[case] text: &str, #[case] expected: BTreeSet<String>) {
+    assert_eq!(expected, tokenize(text));
+}
+
+fn type_names(descriptors: &[TypeDescriptor]) -> Vec<String> {
+    descriptors
+        .iter()
+        .map(|descriptor| descriptor.header().type_name.clone())
+        .collect()
+}
+
+/// This test verifies that descriptors can be found by base_type and by searching their labels
+/// and descriptions, as they are created, updated and deleted.
+///
+/// Test Outline:
+/// 1. Create `age` and `count` Integer descriptors, a `street` String descriptor and a
+///    `location` HolonDescriptor.
+/// 2. Verify `find_value_descriptors_by_base_type` finds the Integer descriptors.
+/// 3. Verify `search_descriptors` matches every query word against the start of the words of
+///    labels and descriptions, across kinds of descriptor.
+/// 4. Update the label of `count` and verify the search follows the new label.
+/// 5. Delete `street` and verify it is no longer found.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_descriptor_index() {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. Create the descriptors
+    let age = new_integer_descriptor(
+        derive_type_name("age", BaseType::Integer, ""),
+        "Age in years".to_string(),
+        "Age".to_string(),
+        false,
+        0,
+        150,
+    )
+    .unwrap();
+    let count = new_integer_descriptor(
+        derive_type_name("count", BaseType::Integer, ""),
+        "Number of items".to_string(),
+        "Count".to_string(),
+        false,
+        0,
+        1000,
+    )
+    .unwrap();
+    let street = new_string_descriptor(
+        derive_type_name("street", BaseType::String, ""),
+        "First line of a postal address".to_string(),
+        "Street Address".to_string(),
+        false,
+        1,
+        64,
+    )
+    .unwrap();
+    let location = new_holon_descriptor(
+        derive_type_name("location", BaseType::Holon, ""),
+        "A place with a postal address".to_string(),
+        "Location".to_string(),
+        false,
+    )
+    .unwrap();
+    let mut records: Vec<Record> = Vec::new();
+    for descriptor in [age.clone(), count.clone(), street.clone()] {
+        let record: Record = conductor
+            .call(&cell.zome("descriptors"), "create_value_descriptor", descriptor)
+            .await;
+        records.push(record);
+    }
+    let _location_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", location.clone())
+        .await;
+
+    // 2. Find by base_type
+    let integers: Vec<ValueDescriptor> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "find_value_descriptors_by_base_type",
+            BaseType::Integer,
+        )
+        .await;
+    assert_eq!(vec![age.clone(), count.clone()], integers);
+    let booleans: Vec<ValueDescriptor> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "find_value_descriptors_by_base_type",
+            BaseType::Boolean,
+        )
+        .await;
+    assert!(booleans.is_empty());
+    println!("Success! ValueDescriptors were found by base_type");
+
+    // 3. Search labels and descriptions
+    let located = vec![location.header.type_name.clone(), street.header.type_name.clone()];
+    for (query, expected) in [
+        ("address", located.clone()),
+        ("POSTAL addr", located),
+        ("street addr", vec![street.header.type_name.clone()]),
+        ("a", vec![]),
+    ] {
+        let found: Vec<TypeDescriptor> = conductor
+            .call(&cell.zome("descriptors"), "search_descriptors", query.to_string())
+            .await;
+        assert_eq!(expected, type_names(&found), "query {query}");
+    }
+    println!("Success! Descriptors were found by searching their labels and descriptions");
+
+    // 4. The search follows updates
+    let mut updated_count = count.clone();
+    updated_count.header.label = "Quantity".to_string();
+    bump_value_descriptor_version(&count, &mut updated_count).unwrap();
+    let count_hash = records[1].action_address().clone();
+    let update_input = UpdateValueDescriptorInput {
+        original_value_descriptor_hash: count_hash.clone(),
+        previous_value_descriptor_hash: count_hash,
+        updated_value_descriptor: updated_count.clone(),
+    };
+    let _updated_record: Record = conductor
+        .call(&cell.zome("descriptors"), "update_value_descriptor", update_input)
+        .await;
+    let found: Vec<TypeDescriptor> = conductor
+        .call(&cell.zome("descriptors"), "search_descriptors", "count".to_string())
+        .await;
+    assert!(found.is_empty());
+    let found: Vec<TypeDescriptor> = conductor
+        .call(&cell.zome("descriptors"), "search_descriptors", "quant".to_string())
+        .await;
+    assert_eq!(vec![TypeDescriptor::Value(updated_count)], found);
+    println!("Success! Search follows the updated label");
+
+    // 5. Deleted descriptors are no longer found
+    let delete_input = DeleteDescriptorInput {
+        original_descriptor_hash: records[2].action_address().clone(),
+        mode: DeletionMode::Restrict,
+    };
+    let _deleted_hashes: Vec<ActionHash> = conductor
+        .call(&cell.zome("descriptors"), "delete_value_descriptor", delete_input)
+        .await;
+    let found: Vec<TypeDescriptor> = conductor
+        .call(&cell.zome("descriptors"), "search_descriptors", "street".to_string())
+        .await;
+    assert!(found.is_empty());
+    let strings: Vec<ValueDescriptor> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "find_value_descriptors_by_base_type",
+            BaseType::String,
+        )
+        .await;
+    assert!(strings.is_empty());
+    println!("Success! Deleted descriptors are no longer found");
+}
+
+/// This test verifies that the index follows updates and deletes made by an agent other than
+/// the one who created the descriptor.
+///
+/// Test Outline:
+/// 1. As one agent, create a `weight` Integer descriptor labelled "Weight".
+/// 2. As another agent, relabel it "Heft", and verify that, for both agents, the search follows
+///    the new label.
+/// 3. As the other agent, delete it, and verify that neither agent finds it any more.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_descriptor_index_across_agents() {
+    let (conductors, cells) = shared_test::setup_conductors(2).await;
+    conductors.exchange_peer_info().await;
+
+    // 1. The author creates weight
+    let weight = new_integer_descriptor(
+        derive_type_name("weight", BaseType::Integer, ""),
+        "Mass in kilograms".to_string(),
+        "Weight".to_string(),
+        false,
+        0,
+        500,
+    )
+    .unwrap();
+    let weight_record: Record = conductors[0]
+        .call(&cells[0].zome("descriptors"), "create_value_descriptor", weight.clone())
+        .await;
+    let weight_hash = weight_record.action_address().clone();
+    consistency_10s([&cells[0], &cells[1]]).await;
+
+    // 2. Another agent relabels it
+    let mut updated_weight = weight.clone();
+    updated_weight.header.label = "Heft".to_string();
+    bump_value_descriptor_version(&weight, &mut updated_weight).unwrap();
+    let update_input = UpdateValueDescriptorInput {
+        original_value_descriptor_hash: weight_hash.clone(),
+        previous_value_descriptor_hash: weight_hash.clone(),
+        updated_value_descriptor: updated_weight.clone(),
+    };
+    let _updated_record: Record = conductors[1]
+        .call(&cells[1].zome("descriptors"), "update_value_descriptor", update_input)
+        .await;
+    consistency_10s([&cells[0], &cells[1]]).await;
+    for (conductor, cell) in conductors.iter().zip(cells.iter()) {
+        let found: Vec<TypeDescriptor> = conductor
+            .call(&cell.zome("descriptors"), "search_descriptors", "weight".to_string())
+            .await;
+        assert!(found.is_empty());
+        let found: Vec<TypeDescriptor> = conductor
+            .call(&cell.zome("descriptors"), "search_descriptors", "heft".to_string())
+            .await;
+        assert_eq!(vec![TypeDescriptor::Value(updated_weight.clone())], found);
+    }
+    println!("Success! Search follows a label updated by another agent");
+
+    // 3. Another agent deletes it
+    let delete_input = DeleteDescriptorInput {
+        original_descriptor_hash: weight_hash,
+        mode: DeletionMode::Restrict,
+    };
+    let _deleted_hashes: Vec<ActionHash> = conductors[1]
+        .call(&cells[1].zome("descriptors"), "delete_value_descriptor", delete_input)
+        .await;
+    consistency_10s([&cells[0], &cells[1]]).await;
+    for (conductor, cell) in conductors.iter().zip(cells.iter()) {
+        let found: Vec<TypeDescriptor> = conductor
+            .call(&cell.zome("descriptors"), "search_descriptors", "heft".to_string())
+            .await;
+        assert!(found.is_empty());
+        let integers: Vec<ValueDescriptor> = conductor
+            .call(
+                &cell.zome("descriptors"),
+                "find_value_descriptors_by_base_type",
+                BaseType::Integer,
+            )
+            .await;
+        assert!(integers.is_empty());
+    }
+    println!("Success! A descriptor deleted by another agent is no longer found");
+}
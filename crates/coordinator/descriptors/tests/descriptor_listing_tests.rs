@@ -0,0 +1,125 @@
+//! Descriptor Listing Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+
+use descriptors::descriptor_listing::{
+    DescriptorPage, ListDescriptorsInput, ListingCursor, ListingOrder,
+};
+use descriptors::helpers::get_value_descriptor_from_record;
+use descriptors::mutators::new_boolean_descriptor;
+use descriptors_integrity::listing_validators::listing_shard;
+use rstest::*;
+use shared_test::fixture_helpers::{derive_label, derive_type_description, derive_type_name};
+use shared_types_descriptor::type_header::BaseType;
+
+/// These tests check which shard of a listing each type_name falls in.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test descriptor_listing_tests -- --show-output
+#[rstest]
+#[case::lowercase("address_Composite_Type", "a")]
+#[case::uppercase("Address_Composite_Type", "a")]
+#[case::digit("9lives_String_Type", "9")]
+#[case::punctuation("-dash_String_Type", "_")]
+#[case::non_ascii("émigré_String_Type", "_")]
+#[case::empty("", "_")]
+fn rstest_listing_shard(#[case] type_name: &str, #[case] expected: &str) {
+    assert_eq!(expected, listing_shard(type_name));
+}
+
+fn type_names(records: &[Record]) -> Vec<String> {
+    records
+        .iter()
+        .map(|record| {
+            get_value_descriptor_from_record(record.clone())
+                .unwrap()
+                .header
+                .type_name
+        })
+        .collect()
+}
+
+fn list_input(order: ListingOrder, after: Option<ListingCursor>) -> ListDescriptorsInput {
+    ListDescriptorsInput {
+        order,
+        limit: 2,
+        after,
+    }
+}
+
+/// This test verifies that a sharded listing can be paged through in either order.
+///
+/// Test Outline:
+/// 1. Create ValueDescriptors whose type_names fall in different shards.
+/// 2. Verify `get_all_value_descriptors` returns them all, in the order they were created.
+/// 3. Page through `list_value_descriptors` two at a time, by type_name (within each shard, in
+///    order) and by creation time, and verify the pages and their cursors.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_descriptor_listing() {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. One descriptor per prefix, each in a different shard but for `Beta` and `alpha`
+    let mut created: Vec<String> = Vec::new();
+    for prefix in ["zeta", "alpha", "Beta", "9lives", "-dash"] {
+        let type_name = derive_type_name(prefix, BaseType::Boolean, "");
+        let descriptor = new_boolean_descriptor(
+            type_name.clone(),
+            derive_type_description(&type_name),
+            derive_label(&type_name),
+            false,
+            false,
+        )
+        .unwrap();
+        let _record: Record = conductor
+            .call(&cell.zome("descriptors"), "create_value_descriptor", descriptor)
+            .await;
+        created.push(type_name);
+    }
+
+    // 2. The unpaged listing
+    let all: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_all_value_descriptors", ())
+        .await;
+    assert_eq!(created, type_names(&all));
+    println!("Success! get_all_value_descriptors gathered every shard");
+
+    // 3. Page through the listing in each order
+    // by type_name, the shards come first
+    let mut by_type_name = created.clone();
+    by_type_name.sort_by_key(|type_name| (listing_shard(type_name), type_name.clone()));
+    for (order, expected) in [
+        (ListingOrder::TypeName, by_type_name),
+        (ListingOrder::CreatedAt, created),
+    ] {
+        let mut listed: Vec<String> = Vec::new();
+        let mut after: Option<ListingCursor> = None;
+        let mut page_count = 0;
+        loop {
+            let page: DescriptorPage = conductor
+                .call(
+                    &cell.zome("descriptors"),
+                    "list_value_descriptors",
+                    list_input(order, after),
+                )
+                .await;
+            page_count += 1;
+            let page_type_names = type_names(&page.records);
+            if let Some(next) = &page.next {
+                assert_eq!(Some(&next.type_name), page_type_names.last());
+            }
+            listed.extend(page_type_names);
+            after = page.next;
+            if after.is_none() {
+                break;
+            }
+        }
+        assert_eq!(3, page_count);
+        assert_eq!(expected, listed);
+        println!("Success! Paged through the listing by {:?}", order);
+    }
+}
@@ -0,0 +1,148 @@
+//! Descriptor Resolution Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+use std::collections::BTreeMap;
+
+use descriptors::descriptor_resolution::{ResolutionMode, ResolveDescriptorInput};
+use descriptors::helpers::get_composite_descriptor_map;
+use descriptors::mutators::{
+    new_composite_descriptor, new_holon_descriptor, new_string_descriptor,
+    update_string_descriptor,
+};
+use descriptors::property_map_builder::upsert_property_descriptor;
+use descriptors::value_descriptor_storage_fns::UpdateValueDescriptorInput;
+use rstest::*;
+use shared_test::fixture_helpers::{
+    derive_label, derive_type_description, derive_type_name, shared_usage,
+};
+use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+use shared_types_descriptor::type_header::BaseType;
+use shared_types_descriptor::value_descriptor::PropertyDescriptorMap;
+
+fn resolve_input(
+    original_hash: &ActionHash,
+    mode: ResolutionMode,
+    max_depth: Option<u32>,
+) -> ResolveDescriptorInput {
+    ResolveDescriptorInput {
+        original_descriptor_hash: original_hash.clone(),
+        mode,
+        max_depth,
+    }
+}
+
+/// This test verifies that shared property usages are replaced by the stored descriptors they
+/// reference when a descriptor is resolved.
+///
+/// Test Outline:
+/// 1. Create a shared string descriptor, a composite that shares it, and a HolonDescriptor whose
+///    property shares the composite.
+/// 2. Update the shared string descriptor.
+/// 3. Resolve the HolonDescriptor with the Latest mode and verify its nested shared usage
+///    carries the update; resolve it with the Pinned mode and verify it carries the original.
+/// 4. Resolve with a max_depth of 1 and verify the nested usage keeps its embedded copy.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test descriptor_resolution_tests -- --show-output
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_descriptor_resolution() {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. Create a chain of shared descriptors: holon -> contact -> handle
+    let type_name = derive_type_name("handle", BaseType::String, "");
+    let handle = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        3,
+        16,
+    )
+    .unwrap();
+    let handle_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", handle.clone())
+        .await;
+
+    let mut contact_properties = PropertyDescriptorMap::new(BTreeMap::new());
+    let handle_usage = shared_usage(&handle, &handle_record);
+    upsert_property_descriptor(&mut contact_properties, "handle".to_string(), &handle_usage);
+    let type_name = derive_type_name("contact", BaseType::Composite, "");
+    let contact = new_composite_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        contact_properties,
+    )
+    .unwrap();
+    let contact_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", contact.clone())
+        .await;
+
+    let type_name = derive_type_name("member", BaseType::Holon, "");
+    let mut member = new_holon_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+    )
+    .unwrap();
+    let contact_usage = shared_usage(&contact, &contact_record);
+    upsert_property_descriptor(&mut member.property_map, "contact".to_string(), &contact_usage);
+    let member_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", member.clone())
+        .await;
+    let member_hash = member_record.action_address().clone();
+
+    // 2. Widen the shared handle descriptor
+    let updated_handle =
+        update_string_descriptor(&handle, None, None, None, Some(32), None, None).unwrap();
+    let update_input = UpdateValueDescriptorInput {
+        original_value_descriptor_hash: handle_record.action_address().clone(),
+        previous_value_descriptor_hash: handle_record.action_address().clone(),
+        updated_value_descriptor: updated_handle.clone(),
+    };
+    let _updated_record: Record = conductor
+        .call(&cell.zome("descriptors"), "update_value_descriptor", update_input)
+        .await;
+
+    // 3. Resolve with each mode
+    for (mode, expected_handle) in [
+        (ResolutionMode::Latest, &updated_handle),
+        (ResolutionMode::Pinned, &handle),
+    ] {
+        let resolved: Option<HolonDescriptor> = conductor
+            .call(
+                &cell.zome("descriptors"),
+                "get_resolved_holon_descriptor",
+                resolve_input(&member_hash, mode, None),
+            )
+            .await;
+        let resolved = resolved.unwrap();
+        let resolved_contact = &resolved.property_map.properties["contact"].descriptor;
+        let resolved_handle =
+            &get_composite_descriptor_map(&resolved_contact.details).properties["handle"];
+        assert_eq!(expected_handle, &resolved_handle.descriptor);
+    }
+    println!("Success! Shared usages resolved to the latest and the pinned revisions");
+
+    // 4. Resolution stops at max_depth, leaving the embedded copy
+    let resolved: Option<HolonDescriptor> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_resolved_holon_descriptor",
+            resolve_input(&member_hash, ResolutionMode::Latest, Some(1)),
+        )
+        .await;
+    let resolved = resolved.unwrap();
+    let resolved_contact = &resolved.property_map.properties["contact"].descriptor;
+    let resolved_handle =
+        &get_composite_descriptor_map(&resolved_contact.details).properties["handle"];
+    assert_eq!(&handle, &resolved_handle.descriptor);
+    println!("Success! Resolution stopped at max_depth");
+}
@@ -0,0 +1,96 @@
+//! Descriptor Validation Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+
+use descriptors::value_descriptor_storage_fns::UpdateValueDescriptorInput;
+use rstest::*;
+use shared_test::invalid_descriptor_fixtures::*;
+use shared_test::test_data_types::ValueDescriptorTestCase;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::value_descriptor::ValueDescriptor;
+
+/// These tests verify that the integrity zome rejects structurally invalid ValueDescriptors.
+/// Each case supplies a descriptor that must NOT be committed.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test descriptor_validation_tests -- --show-output
+#[rstest]
+#[case::header_base_type_disagrees_with_details(mismatched_base_type())]
+#[case::nested_composite_property_disagrees_with_details(mismatched_nested_base_type())]
+#[case::string_min_length_greater_than_max_length(inverted_string_length_range())]
+#[case::integer_min_value_greater_than_max_value(inverted_integer_value_range())]
+#[case::collection_min_items_greater_than_max_items(inverted_collection_item_range())]
+#[case::collection_item_type_without_action_hash(unresolved_collection_item_type())]
+#[case::nested_composite_property_with_inverted_range(inverted_range_in_nested_composite())]
+#[case::string_with_uncompilable_pattern(uncompilable_string_pattern())]
+#[case::enum_without_variants(enum_without_variants())]
+#[case::enum_with_duplicate_variant_names(enum_with_duplicate_variants())]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_invalid_value_descriptors(
+    #[case] input: Result<ValueDescriptor, DescriptorsError>,
+) {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    let descriptor = input.unwrap();
+    println!("Attempting to create invalid descriptor: {:#?}", descriptor);
+
+    let result: Result<Record, _> = conductor
+        .call_fallible(
+            &cell.zome("descriptors"),
+            "create_value_descriptor",
+            descriptor,
+        )
+        .await;
+    assert!(result.is_err());
+    println!("...Success! Invalid descriptor was rejected: {:?} \n", result.err());
+}
+
+/// These tests verify that the integrity zome rejects updates that violate the rules for
+/// revising a descriptor. Each case supplies a valid original and a single update that must NOT
+/// be committed.
+#[rstest]
+#[case::update_changes_type_name(update_changes_type_name())]
+#[case::update_changes_base_type(update_changes_base_type())]
+#[case::update_changes_is_dependent(update_changes_is_dependent())]
+#[case::update_without_version_bump(update_without_version_bump())]
+#[case::update_with_insufficient_version_bump(update_with_insufficient_version_bump())]
+#[case::update_adds_property_with_patch_bump(update_adds_property_with_patch_bump())]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_invalid_value_descriptor_updates(
+    #[case] input: Result<ValueDescriptorTestCase, DescriptorsError>,
+) {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    let test_case = input.unwrap();
+    let created_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "create_value_descriptor",
+            test_case.original.clone(),
+        )
+        .await;
+    let created_action_hash: ActionHash = created_record.action_address().clone();
+
+    for descriptor in test_case.updates {
+        println!("Attempting invalid update: {:#?}", descriptor);
+        let update_input = UpdateValueDescriptorInput {
+            original_value_descriptor_hash: created_action_hash.clone(),
+            previous_value_descriptor_hash: created_action_hash.clone(),
+            updated_value_descriptor: descriptor,
+        };
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("descriptors"),
+                "update_value_descriptor",
+                update_input,
+            )
+            .await;
+        assert!(result.is_err());
+        println!("...Success! Invalid update was rejected: {:?} \n", result.err());
+    }
+}
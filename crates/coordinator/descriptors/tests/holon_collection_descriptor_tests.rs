@@ -0,0 +1,204 @@
+//! Holon Collection Descriptor Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+
+use descriptors::descriptor_deletion::{DeleteDescriptorInput, DeletionMode};
+use descriptors::helpers::get_holon_collection_descriptor_from_record;
+use descriptors::holon_collection_descriptor_storage_fns::UpdateHolonCollectionDescriptorInput;
+use descriptors::mutators::{
+    new_holon_collection_descriptor, new_string_descriptor, update_holon_collection_descriptor,
+};
+use rstest::*;
+use shared_test::fixture_helpers::{derive_label, derive_type_description, derive_type_name};
+use shared_test::holon_fixtures::book_holon_descriptor;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::holon_descriptor::{
+    HolonCollectionDescriptor, HolonDescriptor, HolonReference,
+};
+use shared_types_descriptor::type_header::BaseType;
+
+fn collection_of(prefix: &str, item_type: HolonReference) -> HolonCollectionDescriptor {
+    let type_name = derive_type_name(prefix, BaseType::Collection, "");
+    new_holon_collection_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        item_type,
+        0,
+        20,
+        true,
+        true,
+    )
+    .unwrap()
+}
+
+/// This test exercises the lifecycle of a HolonCollectionDescriptor and verifies that its item
+/// type must be a stored HolonDescriptor.
+///
+/// Test Outline:
+/// 1. Create a HolonDescriptor, then a HolonCollectionDescriptor of it, and verify
+///    `get_holon_collection_descriptor` and `get_all_holon_collection_types` return it.
+/// 2. Widen its item count range and verify `get_holon_collection_descriptor` returns the update,
+///    then verify that staging a change to `is_ordered` bumps the major version.
+/// 3. Attempt to create HolonCollectionDescriptors whose items are a ValueDescriptor, or that
+///    have an inverted item count range, and verify both are rejected.
+/// 4. Delete the updated HolonCollectionDescriptor and verify neither
+///    `get_holon_collection_descriptor` nor `get_all_holon_collection_types` returns it, and that
+///    its item type can then be deleted with the Restrict mode.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test holon_collection_descriptor_tests -- --show-output
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_holon_collection_descriptor_capabilities(
+    book_holon_descriptor: Result<HolonDescriptor, DescriptorsError>,
+) {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. Create the item type, then the collection
+    let book = book_holon_descriptor.unwrap();
+    let book_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", book.clone())
+        .await;
+    let book_reference = HolonReference::new(
+        Some(book_record.action_address().clone()),
+        Some(book.header.type_name.clone()),
+    );
+
+    let bookshelf = collection_of("bookshelf", book_reference.clone());
+    let created_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "create_holon_collection_descriptor",
+            bookshelf.clone(),
+        )
+        .await;
+    let original_hash: ActionHash = created_record.action_address().clone();
+
+    let fetched_record: Option<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_holon_collection_descriptor",
+            original_hash.clone(),
+        )
+        .await;
+    let fetched = get_holon_collection_descriptor_from_record(fetched_record.unwrap()).unwrap();
+    assert_eq!(bookshelf, fetched);
+
+    let all_collection_types: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_all_holon_collection_types", ())
+        .await;
+    assert_eq!(1, all_collection_types.len());
+    println!("Success! HolonCollectionDescriptor created");
+
+    // 2. Widen the item count range
+    let updated =
+        update_holon_collection_descriptor(&bookshelf, None, None, None, Some(50), None, None)
+            .unwrap();
+    let update_input = UpdateHolonCollectionDescriptorInput {
+        original_holon_collection_descriptor_hash: original_hash.clone(),
+        previous_holon_collection_descriptor_hash: original_hash.clone(),
+        updated_holon_collection_descriptor: updated.clone(),
+    };
+    let _updated_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "update_holon_collection_descriptor",
+            update_input,
+        )
+        .await;
+    let fetched_record: Option<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_holon_collection_descriptor",
+            original_hash.clone(),
+        )
+        .await;
+    let fetched = get_holon_collection_descriptor_from_record(fetched_record.unwrap()).unwrap();
+    assert_eq!(updated, fetched);
+    let unordered =
+        update_holon_collection_descriptor(&updated, None, None, None, None, None, Some(false))
+            .unwrap();
+    assert!(!unordered.is_ordered);
+    assert_eq!(updated.header.version.major() + 1, unordered.header.version.major());
+    println!("Success! HolonCollectionDescriptor updated");
+
+    // 3. Invalid collections are rejected
+    let type_name = derive_type_name("not_a_holon", BaseType::String, "");
+    let string_descriptor = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        0,
+        10,
+    )
+    .unwrap();
+    let string_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "create_value_descriptor",
+            string_descriptor,
+        )
+        .await;
+    let of_value_descriptor = collection_of(
+        "of_values",
+        HolonReference::new(Some(string_record.action_address().clone()), Some(type_name)),
+    );
+
+    // the mutator refuses to stage an inverted range, so it is tampered with afterwards
+    let mut inverted_range = collection_of("inverted", book_reference);
+    inverted_range.min_items = 30;
+
+    for invalid in [of_value_descriptor, inverted_range] {
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("descriptors"),
+                "create_holon_collection_descriptor",
+                invalid,
+            )
+            .await;
+        assert!(result.is_err());
+    }
+    println!("Success! Invalid HolonCollectionDescriptors were rejected");
+
+    // 4. Deleting the collection deletes every revision of it
+    let delete_input = DeleteDescriptorInput {
+        original_descriptor_hash: original_hash.clone(),
+        mode: DeletionMode::Restrict,
+    };
+    let deleted: Vec<ActionHash> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "delete_holon_collection_descriptor",
+            delete_input,
+        )
+        .await;
+    assert_eq!(vec![original_hash.clone()], deleted);
+    let fetched_record: Option<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_holon_collection_descriptor",
+            original_hash,
+        )
+        .await;
+    assert!(fetched_record.is_none());
+    let all_collection_types: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_all_holon_collection_types", ())
+        .await;
+    assert!(all_collection_types.is_empty());
+    let delete_input = DeleteDescriptorInput {
+        original_descriptor_hash: book_record.action_address().clone(),
+        mode: DeletionMode::Restrict,
+    };
+    let deleted: Vec<ActionHash> = conductor
+        .call(&cell.zome("descriptors"), "delete_holon_descriptor", delete_input)
+        .await;
+    assert_eq!(vec![book_record.action_address().clone()], deleted);
+    println!("Success! HolonCollectionDescriptor deleted, no longer holding on to its item type");
+}
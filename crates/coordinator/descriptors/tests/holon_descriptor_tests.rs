@@ -11,6 +11,7 @@ use hdk::prelude::*;
 use holochain::sweettest::{SweetCell, SweetConductor};
 //use std::arch::x86_64::__cpuid_count;
 use async_std::task;
+use descriptors::descriptor_deletion::{DeleteDescriptorInput, DeletionMode};
 use descriptors::helpers::get_holon_descriptor_from_record;
 use descriptors::holon_descriptor_storage_fns::UpdateHolonDescriptorInput;
 use descriptors::mutators::{
@@ -139,11 +140,15 @@ async fn rstest_holon_descriptor_capabilities(
     println!("\n\n *********** TESTING DELETES *******************\n");
 
     for hash in created_action_hashes {
-        let _action_hash_of_delete: ActionHash = conductor
+        let delete_input = DeleteDescriptorInput {
+            original_descriptor_hash: hash.clone(),
+            mode: DeletionMode::Restrict,
+        };
+        let _deleted_hashes: Vec<ActionHash> = conductor
             .call(
                 &cell.zome("descriptors"),
                 "delete_holon_descriptor",
-                hash.clone(),
+                delete_input,
             )
             .await;
         let try_query: Option<Record> = conductor
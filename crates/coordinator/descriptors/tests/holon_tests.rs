@@ -0,0 +1,183 @@
+//! Holon Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+use holochain::test_utils::consistency_10s;
+
+use descriptors::helpers::get_holon_from_record;
+use descriptors::holon_storage_fns::UpdateHolonInput;
+use descriptors::mutators::new_holon;
+use rstest::*;
+use shared_test::holon_fixtures::{book_holon_descriptor, book_values};
+use shared_types_descriptor::base_value::BaseValue;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::holon::Holon;
+use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+
+/// This test exercises the lifecycle of a Holon and the validation of its property values
+/// against its HolonDescriptor.
+///
+/// Test Outline:
+/// 1. Create a HolonDescriptor, then create a conforming Holon and verify `get_holon` and
+///    `get_holons_of_type` return it.
+/// 2. Attempt to create Holons whose properties are missing, unknown or out of range, and
+///    verify that each is rejected.
+/// 3. Update the Holon and verify `get_holon` returns the update.
+/// 4. Delete the Holon and verify it is no longer listed by `get_holons_of_type`.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test holon_tests -- --show-output
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_holon_capabilities(
+    book_holon_descriptor: Result<HolonDescriptor, DescriptorsError>,
+) {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    let descriptor = book_holon_descriptor.unwrap();
+    let descriptor_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "create_holon_descriptor",
+            descriptor.clone(),
+        )
+        .await;
+    let descriptor_hash: ActionHash = descriptor_record.action_address().clone();
+
+    // 1. Create and get a conforming Holon
+    let holon = new_holon(
+        descriptor_hash.clone(),
+        descriptor_hash.clone(),
+        &descriptor,
+        book_values("Middlemarch", 880),
+    )
+    .unwrap();
+    let created_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon", holon.clone())
+        .await;
+    let holon_hash: ActionHash = created_record.action_address().clone();
+
+    let fetched_record: Option<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_holon", holon_hash.clone())
+        .await;
+    let fetched_holon = get_holon_from_record(fetched_record.unwrap()).unwrap();
+    assert_eq!(holon, fetched_holon);
+
+    let holons_of_type: Vec<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_holons_of_type",
+            descriptor_hash.clone(),
+        )
+        .await;
+    assert_eq!(1, holons_of_type.len());
+    println!("Success! Holon created and listed as an instance of its HolonDescriptor");
+
+    // 2. Non-conforming Holons are rejected
+    let mut missing_property = book_values("Middlemarch", 880);
+    missing_property.remove("pages");
+    let mut unknown_property = book_values("Middlemarch", 880);
+    unknown_property.insert(
+        "author".to_string(),
+        BaseValue::String("George Eliot".to_string()),
+    );
+    let out_of_range = book_values("", 880);
+    for property_map in [missing_property, unknown_property, out_of_range] {
+        // the mutator refuses to stage these, so the Holon is assembled directly
+        let invalid_holon = Holon::new(
+            descriptor_hash.clone(),
+            descriptor_hash.clone(),
+            descriptor.header.version.clone(),
+            property_map,
+        );
+        let result: Result<Record, _> = conductor
+            .call_fallible(&cell.zome("descriptors"), "create_holon", invalid_holon)
+            .await;
+        assert!(result.is_err());
+    }
+    println!("Success! Non-conforming Holons were rejected");
+
+    // 3. Update the Holon
+    let mut updated_holon = holon.clone();
+    updated_holon.property_map = book_values("Middlemarch: A Study of Provincial Life", 880);
+    let update_input = UpdateHolonInput {
+        original_holon_hash: holon_hash.clone(),
+        previous_holon_hash: holon_hash.clone(),
+        updated_holon: updated_holon.clone(),
+    };
+    let _updated_record: Record = conductor
+        .call(&cell.zome("descriptors"), "update_holon", update_input)
+        .await;
+    let fetched_record: Option<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_holon", holon_hash.clone())
+        .await;
+    let fetched_holon = get_holon_from_record(fetched_record.unwrap()).unwrap();
+    assert_eq!(updated_holon, fetched_holon);
+    println!("Success! Holon updated");
+
+    // 4. Delete the Holon
+    let _delete_hash: ActionHash = conductor
+        .call(&cell.zome("descriptors"), "delete_holon", holon_hash.clone())
+        .await;
+    let holons_of_type: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_holons_of_type", descriptor_hash)
+        .await;
+    assert_eq!(0, holons_of_type.len());
+    println!("Success! Holon deleted");
+}
+
+/// This test verifies that a Holon can be deleted by an agent other than its author, along with
+/// the HolonsOfType link that lists it.
+///
+/// Test Outline:
+/// 1. As one agent, create a HolonDescriptor and a conforming Holon.
+/// 2. As another agent, delete the Holon.
+/// 3. Verify that neither agent lists the Holon among the instances of its type any more.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_holon_deleted_by_another_agent(
+    book_holon_descriptor: Result<HolonDescriptor, DescriptorsError>,
+) {
+    let (conductors, cells) = shared_test::setup_conductors(2).await;
+    conductors.exchange_peer_info().await;
+
+    // 1. The author creates a Holon
+    let descriptor = book_holon_descriptor.unwrap();
+    let descriptor_record: Record = conductors[0]
+        .call(&cells[0].zome("descriptors"), "create_holon_descriptor", descriptor.clone())
+        .await;
+    let descriptor_hash: ActionHash = descriptor_record.action_address().clone();
+    let holon = new_holon(
+        descriptor_hash.clone(),
+        descriptor_hash.clone(),
+        &descriptor,
+        book_values("Middlemarch", 880),
+    )
+    .unwrap();
+    let created_record: Record = conductors[0]
+        .call(&cells[0].zome("descriptors"), "create_holon", holon)
+        .await;
+    consistency_10s([&cells[0], &cells[1]]).await;
+
+    // 2. Another agent deletes it
+    let _delete_hash: ActionHash = conductors[1]
+        .call(
+            &cells[1].zome("descriptors"),
+            "delete_holon",
+            created_record.action_address().clone(),
+        )
+        .await;
+    consistency_10s([&cells[0], &cells[1]]).await;
+
+    // 3. It is no longer listed
+    for (conductor, cell) in conductors.iter().zip(cells.iter()) {
+        let holons_of_type: Vec<Record> = conductor
+            .call(&cell.zome("descriptors"), "get_holons_of_type", descriptor_hash.clone())
+            .await;
+        assert_eq!(0, holons_of_type.len());
+    }
+    println!("Success! Holon deleted by another agent");
+}
@@ -0,0 +1,237 @@
+//! Relationship Descriptor Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+
+use descriptors::descriptor_deletion::{DeleteDescriptorInput, DeletionMode};
+use descriptors::helpers::get_relationship_descriptor_from_record;
+use descriptors::mutators::{
+    new_holon_descriptor, new_relationship_descriptor, new_string_descriptor,
+    update_relationship_descriptor,
+};
+use descriptors::relationship_descriptor_storage_fns::UpdateRelationshipDescriptorInput;
+use rstest::*;
+use shared_test::fixture_helpers::{derive_label, derive_type_description, derive_type_name};
+use shared_test::holon_fixtures::book_holon_descriptor;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::holon_descriptor::{HolonDescriptor, HolonReference};
+use shared_types_descriptor::relationship_descriptor::{DeletionSemantic, RelationshipDescriptor};
+use shared_types_descriptor::type_header::BaseType;
+
+fn reference_to(record: &Record, type_name: &str) -> HolonReference {
+    HolonReference::new(
+        Some(record.action_address().clone()),
+        Some(type_name.to_string()),
+    )
+}
+
+fn written_by(source_type: HolonReference, target_type: HolonReference) -> RelationshipDescriptor {
+    let type_name = derive_type_name("written_by", BaseType::Relationship, "");
+    new_relationship_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        source_type,
+        target_type,
+        1,
+        5,
+        Some("author_of".to_string()),
+        true,
+        DeletionSemantic::Allow,
+    )
+    .unwrap()
+}
+
+/// This test exercises the lifecycle of a RelationshipDescriptor and verifies that its source
+/// and target types must be existing HolonDescriptors.
+///
+/// Test Outline:
+/// 1. Create two HolonDescriptors, then a RelationshipDescriptor between them, and verify
+///    `get_relationship_descriptor` and `get_all_relationship_types` return it.
+/// 2. Update its cardinality and verify `get_relationship_descriptor` returns the update.
+/// 3. Attempt to create RelationshipDescriptors whose target is a ValueDescriptor, or that have
+///    an inverted cardinality range, and verify both are rejected.
+/// 4. Delete the updated RelationshipDescriptor and verify neither `get_relationship_descriptor`
+///    nor `get_all_relationship_types` returns it, and that its target type can then be deleted
+///    with the Restrict mode.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test relationship_descriptor_tests -- --show-output
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_relationship_descriptor_capabilities(
+    book_holon_descriptor: Result<HolonDescriptor, DescriptorsError>,
+) {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. Create the holon types, then the relationship between them
+    let book = book_holon_descriptor.unwrap();
+    let type_name = derive_type_name("author", BaseType::Holon, "");
+    let author = new_holon_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+    )
+    .unwrap();
+    let book_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", book.clone())
+        .await;
+    let author_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", author.clone())
+        .await;
+
+    let relationship = written_by(
+        reference_to(&book_record, &book.header.type_name),
+        reference_to(&author_record, &author.header.type_name),
+    );
+    let created_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "create_relationship_descriptor",
+            relationship.clone(),
+        )
+        .await;
+    let original_hash: ActionHash = created_record.action_address().clone();
+
+    let fetched_record: Option<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_relationship_descriptor",
+            original_hash.clone(),
+        )
+        .await;
+    let fetched = get_relationship_descriptor_from_record(fetched_record.unwrap()).unwrap();
+    assert_eq!(relationship, fetched);
+
+    let all_relationship_types: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_all_relationship_types", ())
+        .await;
+    assert_eq!(1, all_relationship_types.len());
+    println!("Success! RelationshipDescriptor created");
+
+    // 2. Widening the cardinality is a minor change
+    let updated = update_relationship_descriptor(
+        &relationship,
+        None,
+        None,
+        Some(0),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    let update_input = UpdateRelationshipDescriptorInput {
+        original_relationship_descriptor_hash: original_hash.clone(),
+        previous_relationship_descriptor_hash: original_hash.clone(),
+        updated_relationship_descriptor: updated.clone(),
+    };
+    let _updated_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "update_relationship_descriptor",
+            update_input,
+        )
+        .await;
+    let fetched_record: Option<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_relationship_descriptor",
+            original_hash.clone(),
+        )
+        .await;
+    let fetched = get_relationship_descriptor_from_record(fetched_record.unwrap()).unwrap();
+    assert_eq!(updated, fetched);
+    println!("Success! RelationshipDescriptor updated");
+
+    // 3. Invalid relationships are rejected
+    let type_name = derive_type_name("not_a_holon", BaseType::String, "");
+    let string_descriptor = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        0,
+        10,
+    )
+    .unwrap();
+    let string_record: Record = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "create_value_descriptor",
+            string_descriptor,
+        )
+        .await;
+
+    let mut targets_value_descriptor = written_by(
+        reference_to(&book_record, &book.header.type_name),
+        reference_to(&string_record, &type_name),
+    );
+    targets_value_descriptor.header.type_name =
+        derive_type_name("targets_value", BaseType::Relationship, "");
+
+    // the mutator refuses to stage an inverted range, so it is tampered with afterwards
+    let mut inverted_cardinality = written_by(
+        reference_to(&book_record, &book.header.type_name),
+        reference_to(&author_record, &author.header.type_name),
+    );
+    inverted_cardinality.header.type_name =
+        derive_type_name("inverted", BaseType::Relationship, "");
+    inverted_cardinality.min_target_cardinality = 10;
+
+    let invalid_descriptors = [
+        (targets_value_descriptor, "must be a HolonDescriptor"),
+        (inverted_cardinality, "is greater than max_target_cardinality"),
+    ];
+    for (invalid, expected_message) in invalid_descriptors {
+        let result: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("descriptors"),
+                "create_relationship_descriptor",
+                invalid,
+            )
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        assert!(error.contains(expected_message), "{error}");
+    }
+    println!("Success! Invalid RelationshipDescriptors were rejected");
+
+    // 4. Deleting the relationship deletes every revision of it
+    let delete_input = DeleteDescriptorInput {
+        original_descriptor_hash: original_hash.clone(),
+        mode: DeletionMode::Restrict,
+    };
+    let deleted: Vec<ActionHash> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "delete_relationship_descriptor",
+            delete_input,
+        )
+        .await;
+    assert_eq!(vec![original_hash.clone()], deleted);
+    let fetched_record: Option<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_relationship_descriptor",
+            original_hash,
+        )
+        .await;
+    assert!(fetched_record.is_none());
+    let all_relationship_types: Vec<Record> = conductor
+        .call(&cell.zome("descriptors"), "get_all_relationship_types", ())
+        .await;
+    assert!(all_relationship_types.is_empty());
+    let delete_input = DeleteDescriptorInput {
+        original_descriptor_hash: author_record.action_address().clone(),
+        mode: DeletionMode::Restrict,
+    };
+    let deleted: Vec<ActionHash> = conductor
+        .call(&cell.zome("descriptors"), "delete_holon_descriptor", delete_input)
+        .await;
+    assert_eq!(vec![author_record.action_address().clone()], deleted);
+    println!("Success! RelationshipDescriptor deleted, no longer holding on to its target type");
+}
@@ -0,0 +1,267 @@
+//! Schema Bundle Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+use std::collections::BTreeMap;
+
+use descriptors::helpers::{get_composite_descriptor_map, get_holon_reference_from_sharing};
+use descriptors::mutators::{
+    new_composite_descriptor, new_holon_descriptor, new_integer_descriptor, new_string_descriptor,
+};
+use descriptors::property_map_builder::upsert_property_descriptor;
+use descriptors::schema_bundles::ExportSchemaBundleInput;
+use rstest::*;
+use shared_test::fixture_helpers::{
+    derive_label, derive_type_description, derive_type_name, shared_usage, sharing,
+    string_descriptor,
+};
+use shared_types_descriptor::holon_descriptor::HolonReference;
+use shared_types_descriptor::schema_bundle::SchemaBundle;
+use shared_types_descriptor::type_header::{BaseType, SemanticVersion};
+use shared_types_descriptor::value_descriptor::{
+    DescriptorSharing, PropertyDescriptorMap, ValueDescriptor,
+};
+
+fn export_input(type_names: Option<Vec<String>>) -> ExportSchemaBundleInput {
+    ExportSchemaBundleInput {
+        name: "address_book".to_string(),
+        version: SemanticVersion::new(1, 2, 0),
+        type_names,
+    }
+}
+
+/// This test verifies that a schema can be exported from one registry as a SchemaBundle and
+/// imported into another.
+///
+/// Test Outline:
+/// 1. Create a `person` HolonDescriptor that shares an `address` composite, which in turn shares
+///    a `street` string, plus an unrelated `age` integer.
+/// 2. Export `person` alone and verify the bundle also holds the types it references, by
+///    type_name only.
+/// 3. Export the whole registry and verify the bundle holds every descriptor.
+/// 4. Import the bundle of `person` into a second conductor, verify its references resolve to
+///    the descriptors imported with it, and that exporting it again yields the same bundle.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_schema_bundle_round_trip() {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. A person, shared address and street, and an unrelated age
+    let type_name = derive_type_name("street", BaseType::String, "");
+    let street = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        1,
+        64,
+    )
+    .unwrap();
+    let street_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", street.clone())
+        .await;
+    let type_name = derive_type_name("address", BaseType::Composite, "");
+    let address = new_composite_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        sharing(&street, &street_record),
+    )
+    .unwrap();
+    let address_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", address.clone())
+        .await;
+    let type_name = derive_type_name("person", BaseType::Holon, "");
+    let mut person = new_holon_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+    )
+    .unwrap();
+    person.property_map = sharing(&address, &address_record);
+    let _person_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", person.clone())
+        .await;
+    let type_name = derive_type_name("age", BaseType::Integer, "");
+    let age = new_integer_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        0,
+        150,
+    )
+    .unwrap();
+    let _age_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", age.clone())
+        .await;
+
+    // 2. Export a subset of the registry
+    let person_input = export_input(Some(vec![person.header.type_name.clone()]));
+    let bundle: SchemaBundle = conductor
+        .call(&cell.zome("descriptors"), "export_schema_bundle", person_input)
+        .await;
+    assert_eq!("address_book", bundle.name);
+    assert_eq!(SemanticVersion::new(1, 2, 0), bundle.version);
+    assert_eq!(1, bundle.holon_descriptors.len());
+    assert_eq!(person.header, bundle.holon_descriptors[0].header);
+    let value_type_names: Vec<String> = bundle
+        .value_descriptors
+        .iter()
+        .map(|descriptor| descriptor.header.type_name.clone())
+        .collect();
+    assert_eq!(
+        vec![address.header.type_name.clone(), street.header.type_name.clone()],
+        value_type_names
+    );
+    let person_reference = get_holon_reference_from_sharing(
+        &bundle.holon_descriptors[0].property_map.properties["shared"].sharing,
+    );
+    assert_eq!(HolonReference::new(None, Some(address.header.type_name.clone())), person_reference);
+    println!("Success! The bundle of person holds the types it references, by type_name");
+
+    // 3. Export the whole registry
+    let all: SchemaBundle = conductor
+        .call(&cell.zome("descriptors"), "export_schema_bundle", export_input(None))
+        .await;
+    assert_eq!(1, all.holon_descriptors.len());
+    assert_eq!(3, all.value_descriptors.len());
+    assert!(all.value_descriptors.iter().any(|descriptor| descriptor == &age));
+    println!("Success! The bundle of the registry holds every descriptor");
+
+    // 4. Import into another registry, then export it from there
+    let (other_conductor, _other_agent, other_cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+    let imported: Vec<Record> = other_conductor
+        .call(&other_cell.zome("descriptors"), "import_schema_bundle", bundle.clone())
+        .await;
+    assert_eq!(3, imported.len());
+    let imported_address: Option<Record> = other_conductor
+        .call(
+            &other_cell.zome("descriptors"),
+            "get_descriptor_by_type_name",
+            address.header.type_name.clone(),
+        )
+        .await;
+    let imported_street: Option<Record> = other_conductor
+        .call(
+            &other_cell.zome("descriptors"),
+            "get_descriptor_by_type_name",
+            street.header.type_name.clone(),
+        )
+        .await;
+    let imported_address: ValueDescriptor = imported_address
+        .unwrap()
+        .entry()
+        .to_app_option()
+        .unwrap()
+        .unwrap();
+    let street_reference = get_holon_reference_from_sharing(
+        &get_composite_descriptor_map(&imported_address.details).properties["shared"].sharing,
+    );
+    assert_eq!(Some(imported_street.unwrap().action_address().clone()), street_reference.id);
+
+    let person_input = export_input(Some(vec![person.header.type_name.clone()]));
+    let exported_again: SchemaBundle = other_conductor
+        .call(&other_cell.zome("descriptors"), "export_schema_bundle", person_input)
+        .await;
+    assert_eq!(bundle, exported_again);
+    println!("Success! The bundle round-tripped through another registry");
+}
+
+/// This test verifies that references which identify their type by ActionHash alone are named
+/// on export, so that the bundle can be imported.
+///
+/// Test Outline:
+/// 1. Create a `tag` string and a `badge` composite that shares it by ActionHash only.
+/// 2. Export `badge`, and verify the bundle also holds `tag`, referenced by its type_name.
+/// 3. Import the bundle into a second conductor and verify the reference resolves to the `tag`
+///    imported with it, and that exporting it again yields the same bundle.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_schema_bundle_names_references() {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. A badge that shares tag without naming it
+    let tag = string_descriptor("tag");
+    let tag_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", tag.clone())
+        .await;
+    let mut properties = PropertyDescriptorMap::new(BTreeMap::new());
+    let mut usage = shared_usage(&tag, &tag_record);
+    let unnamed_reference = HolonReference::new(Some(tag_record.action_address().clone()), None);
+    usage.sharing = DescriptorSharing::Shared(unnamed_reference);
+    upsert_property_descriptor(&mut properties, "shared".to_string(), &usage);
+    let type_name = derive_type_name("badge", BaseType::Composite, "");
+    let badge = new_composite_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        properties,
+    )
+    .unwrap();
+    let _badge_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", badge.clone())
+        .await;
+
+    // 2. Export badge
+    let badge_input = export_input(Some(vec![badge.header.type_name.clone()]));
+    let bundle: SchemaBundle = conductor
+        .call(&cell.zome("descriptors"), "export_schema_bundle", badge_input)
+        .await;
+    let value_type_names: Vec<String> = bundle
+        .value_descriptors
+        .iter()
+        .map(|descriptor| descriptor.header.type_name.clone())
+        .collect();
+    let expected = vec![badge.header.type_name.clone(), tag.header.type_name.clone()];
+    assert_eq!(expected, value_type_names);
+    let badge_reference = get_holon_reference_from_sharing(
+        &get_composite_descriptor_map(&bundle.value_descriptors[0].details).properties["shared"]
+            .sharing,
+    );
+    assert_eq!(HolonReference::new(None, Some(tag.header.type_name.clone())), badge_reference);
+    println!("Success! The reference was named after the descriptor it identifies");
+
+    // 3. Import into another registry, then export it from there
+    let (other_conductor, _other_agent, other_cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+    let imported: Vec<Record> = other_conductor
+        .call(&other_cell.zome("descriptors"), "import_schema_bundle", bundle.clone())
+        .await;
+    assert_eq!(2, imported.len());
+    let imported_badge: Option<Record> = other_conductor
+        .call(
+            &other_cell.zome("descriptors"),
+            "get_descriptor_by_type_name",
+            badge.header.type_name.clone(),
+        )
+        .await;
+    let imported_tag: Option<Record> = other_conductor
+        .call(
+            &other_cell.zome("descriptors"),
+            "get_descriptor_by_type_name",
+            tag.header.type_name.clone(),
+        )
+        .await;
+    let imported_badge: ValueDescriptor =
+        imported_badge.unwrap().entry().to_app_option().unwrap().unwrap();
+    let tag_reference = get_holon_reference_from_sharing(
+        &get_composite_descriptor_map(&imported_badge.details).properties["shared"].sharing,
+    );
+    assert_eq!(Some(imported_tag.unwrap().action_address().clone()), tag_reference.id);
+
+    let badge_input = export_input(Some(vec![badge.header.type_name.clone()]));
+    let exported_again: SchemaBundle = other_conductor
+        .call(&other_cell.zome("descriptors"), "export_schema_bundle", badge_input)
+        .await;
+    assert_eq!(bundle, exported_again);
+    println!("Success! The bundle with a named reference round-tripped");
+}
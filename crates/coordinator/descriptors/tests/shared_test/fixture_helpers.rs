@@ -1,15 +1,18 @@
 #![allow(dead_code)]
 
-use descriptors::mutators::{new_composite_descriptor, new_holon_descriptor};
+use descriptors::mutators::{
+    new_composite_descriptor, new_holon_descriptor, new_string_descriptor,
+};
 use descriptors::property_map_builder::upsert_property_descriptor;
+use hdk::prelude::*;
 use std::collections::btree_map::BTreeMap;
 
-// use hdk::prelude::*;
 use crate::shared_test::property_descriptor_data_creators::create_example_property_descriptors;
 use shared_types_descriptor::error::DescriptorsError;
-use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+use shared_types_descriptor::holon_descriptor::{HolonDescriptor, HolonReference};
 use shared_types_descriptor::value_descriptor::{
-    DescriptorSharing, ValueDescriptor, PropertyDescriptorMap, PropertyDescriptorUsage,
+    DescriptorSharing, EnumVariant, ValueDescriptor, PropertyDescriptorMap,
+    PropertyDescriptorUsage,
 };
 use shared_types_descriptor::type_header::BaseType;
 
@@ -40,6 +43,86 @@ pub fn derive_type_description(type_name: &str) -> String {
     format!("description for {type_name}")
 }
 
+/// Builds an ordered list of enum variants, one per name, with codes 1, 2, 3...
+pub fn derive_enum_variants(names: &[&str]) -> Vec<EnumVariant> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            EnumVariant::new(
+                name.to_string(),
+                derive_label(name),
+                derive_type_description(name),
+                Some(i as i64 + 1),
+            )
+        })
+        .collect()
+}
+
+/// Builds a string descriptor named after `prefix`, with lengths of 1 to 64
+pub fn string_descriptor(prefix: &str) -> ValueDescriptor {
+    let type_name = derive_type_name(prefix, BaseType::String, "");
+    new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        1,
+        64,
+    )
+    .unwrap()
+}
+
+/// Builds a usage that shares `descriptor`, referenced by type_name and, if given, by ActionHash
+pub fn shared_usage_by(
+    descriptor: &ValueDescriptor,
+    action_hash: Option<ActionHash>,
+) -> PropertyDescriptorUsage {
+    PropertyDescriptorUsage::new(
+        format!("a shared {} property", descriptor.header.type_name),
+        descriptor.clone(),
+        descriptor.header.label.clone(),
+        DescriptorSharing::Shared(HolonReference::new(
+            action_hash,
+            Some(descriptor.header.type_name.clone()),
+        )),
+    )
+}
+
+/// Builds a usage that shares the stored `descriptor`, whose original is `record`
+pub fn shared_usage(descriptor: &ValueDescriptor, record: &Record) -> PropertyDescriptorUsage {
+    shared_usage_by(descriptor, Some(record.action_address().clone()))
+}
+
+/// Builds a property map whose single `shared` property shares the stored `descriptor`
+pub fn sharing(descriptor: &ValueDescriptor, record: &Record) -> PropertyDescriptorMap {
+    let mut properties = PropertyDescriptorMap::new(BTreeMap::new());
+    let usage = shared_usage(descriptor, record);
+    upsert_property_descriptor(&mut properties, "shared".to_string(), &usage);
+    properties
+}
+
+/// Builds a composite with a single `shared` property that shares `shared`, referenced by
+/// type_name and, if given, by ActionHash
+pub fn composite_sharing(
+    prefix: &str,
+    shared: &ValueDescriptor,
+    action_hash: Option<ActionHash>,
+) -> ValueDescriptor {
+    let mut properties = PropertyDescriptorMap::new(BTreeMap::new());
+    let usage = shared_usage_by(shared, action_hash);
+    upsert_property_descriptor(&mut properties, "shared".to_string(), &usage);
+    let type_name = derive_type_name(prefix, BaseType::Composite, "");
+    new_composite_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        properties,
+    )
+    .unwrap()
+}
+
 /// This function creates a rich test dataset by creating a vector of HolonDescriptors of various
 /// kinds -- from simple to complex
 
@@ -145,9 +145,12 @@ pub fn add_properties() -> Result<HolonDescriptorTestCase, DescriptorsError> {
     let properties: PropertyDescriptorMap =
         create_example_property_descriptors(&mut updated_descriptor.property_map)?;
 
+    let mut previous_descriptor = original_descriptor.clone();
     for (name, property) in properties.properties {
         upsert_property_descriptor(&mut updated_descriptor.property_map, name, &property);
+        bump_holon_descriptor_version(&previous_descriptor, &mut updated_descriptor)?;
         updates.push(updated_descriptor.clone());
+        previous_descriptor = updated_descriptor.clone();
     }
 
     let test_case = HolonDescriptorTestCase {
@@ -166,9 +169,12 @@ pub fn remove_properties() -> Result<HolonDescriptorTestCase, DescriptorsError>
     let mut updated_descriptor = original_descriptor.clone();
     let mut updates = Vec::new();
 
+    let mut previous_descriptor = original_descriptor.clone();
     for (name, _property) in original_descriptor.property_map.properties.clone() {
         remove_property_descriptor(&mut updated_descriptor.property_map, name);
+        bump_holon_descriptor_version(&previous_descriptor, &mut updated_descriptor)?;
         updates.push(updated_descriptor.clone());
+        previous_descriptor = updated_descriptor.clone();
     }
 
     let test_case = HolonDescriptorTestCase {
@@ -191,9 +197,12 @@ pub fn update_each_scalar_details() -> Result<HolonDescriptorTestCase, Descripto
     let update_properties =
         create_example_updates_for_property_descriptors(&mut updated_descriptor.property_map)?;
 
+    let mut previous_descriptor = original_descriptor.clone();
     for (name, property) in update_properties.properties.clone() {
         upsert_property_descriptor(&mut updated_descriptor.property_map, name, &property);
+        bump_holon_descriptor_version(&previous_descriptor, &mut updated_descriptor)?;
         updates.push(updated_descriptor.clone());
+        previous_descriptor = updated_descriptor.clone();
     }
 
     let test_case = HolonDescriptorTestCase {
@@ -296,6 +305,7 @@ pub fn add_properties_to_composite() -> Result<HolonDescriptorTestCase, Descript
             "a_composite_property".to_string(),
             &composite_usage,
         );
+        bump_holon_descriptor_version(&original_descriptor, &mut updated_descriptor)?;
         updates.push(updated_descriptor);
 
         let test_case = HolonDescriptorTestCase {
@@ -355,6 +365,7 @@ pub fn remove_properties_from_composite(
             "a_composite_property".to_string(),
             &composite_usage,
         );
+        bump_holon_descriptor_version(&original_descriptor, &mut updated_descriptor)?;
         updates.push(updated_descriptor);
 
         let test_case = HolonDescriptorTestCase {
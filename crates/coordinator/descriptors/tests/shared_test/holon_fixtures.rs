@@ -0,0 +1,79 @@
+// Holon Dataset Creator
+//
+// Holons can only be staged once their HolonDescriptor has been committed (they reference it by
+// ActionHash), so these fixtures supply the descriptor and the property values separately.
+
+#![allow(dead_code)]
+
+use descriptors::mutators::{new_holon_descriptor, new_integer_descriptor, new_string_descriptor};
+use descriptors::property_map_builder::upsert_property_descriptor;
+use rstest::*;
+use std::collections::BTreeMap;
+
+use crate::shared_test::fixture_helpers::{derive_label, derive_type_description, derive_type_name};
+use shared_types_descriptor::base_value::BaseValue;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+use shared_types_descriptor::type_header::BaseType;
+use shared_types_descriptor::value_descriptor::{DescriptorSharing, PropertyDescriptorUsage};
+
+/// A HolonDescriptor with a bounded string `title` and a bounded integer `pages`
+#[fixture]
+pub fn book_holon_descriptor() -> Result<HolonDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("book", BaseType::Holon, "");
+    let mut descriptor = new_holon_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+    )?;
+
+    let type_name = derive_type_name("title", BaseType::String, "");
+    let title = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        1,
+        100,
+    )?;
+    upsert_property_descriptor(
+        &mut descriptor.property_map,
+        "title".to_string(),
+        &PropertyDescriptorUsage::new(
+            "the title of the book".to_string(),
+            title,
+            "title".to_string(),
+            DescriptorSharing::default(),
+        ),
+    );
+
+    let type_name = derive_type_name("pages", BaseType::Integer, "");
+    let pages = new_integer_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        1,
+        10000,
+    )?;
+    upsert_property_descriptor(
+        &mut descriptor.property_map,
+        "pages".to_string(),
+        &PropertyDescriptorUsage::new(
+            "the number of pages in the book".to_string(),
+            pages,
+            "pages".to_string(),
+            DescriptorSharing::default(),
+        ),
+    );
+
+    Ok(descriptor)
+}
+
+pub fn book_values(title: &str, pages: i64) -> BTreeMap<String, BaseValue> {
+    BTreeMap::from([
+        ("title".to_string(), BaseValue::String(title.to_string())),
+        ("pages".to_string(), BaseValue::Integer(pages)),
+    ])
+}
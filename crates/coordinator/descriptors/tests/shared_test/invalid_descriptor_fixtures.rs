@@ -0,0 +1,361 @@
+// Invalid Descriptor Dataset Creator
+//
+// Each fixture in this file builds a descriptor that the integrity zome is expected to reject.
+// Because the mutators apply the same structural checks, these descriptors are assembled (or
+// tampered with) directly rather than via the new_xxx_descriptor functions.
+
+#![allow(dead_code)]
+
+use descriptors::mutators::{
+    new_composite_descriptor, new_enum_descriptor, new_integer_descriptor, new_string_descriptor,
+    new_value_collection_descriptor, update_string_descriptor,
+};
+use descriptors::property_map_builder::upsert_property_descriptor;
+use rstest::*;
+use std::collections::btree_map::BTreeMap;
+
+use crate::shared_test::fixture_helpers::{
+    derive_enum_variants, derive_label, derive_type_description, derive_type_name,
+};
+use crate::shared_test::test_data_types::ValueDescriptorTestCase;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::holon_descriptor::HolonReference;
+use shared_types_descriptor::type_header::{BaseType, SemanticVersion};
+use shared_types_descriptor::value_descriptor::{
+    DescriptorSharing, PropertyDescriptorMap, PropertyDescriptorUsage, ValueDescriptor,
+    ValueDescriptorDetails, ValueItemType,
+};
+
+#[fixture]
+pub fn mismatched_base_type() -> Result<ValueDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("mismatched", BaseType::String, "");
+    let mut descriptor = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        0,
+        100,
+    )?;
+    descriptor.header.base_type = BaseType::Integer;
+    Ok(descriptor)
+}
+
+#[fixture]
+pub fn mismatched_nested_base_type() -> Result<ValueDescriptor, DescriptorsError> {
+    let mut tampered_string = mismatched_base_type()?;
+    tampered_string.header.is_dependent = true;
+    let usage = PropertyDescriptorUsage::new(
+        "a string property whose header claims to be an integer".to_string(),
+        tampered_string,
+        "a mismatched property".to_string(),
+        DescriptorSharing::default(),
+    );
+
+    let type_name = derive_type_name("mismatched", BaseType::Composite, "");
+    let mut descriptor = new_composite_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        PropertyDescriptorMap::new(BTreeMap::new()),
+    )?;
+    if let ValueDescriptorDetails::Composite(ref mut composite) = descriptor.details {
+        upsert_property_descriptor(
+            &mut composite.property_map,
+            "a_mismatched_property".to_string(),
+            &usage,
+        );
+    }
+    Ok(descriptor)
+}
+
+#[fixture]
+pub fn inverted_string_length_range() -> Result<ValueDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("inverted", BaseType::String, "");
+    let mut descriptor = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        0,
+        10,
+    )?;
+    if let ValueDescriptorDetails::String(ref mut string) = descriptor.details {
+        string.min_length = 20;
+    }
+    Ok(descriptor)
+}
+
+#[fixture]
+pub fn uncompilable_string_pattern() -> Result<ValueDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("unparseable", BaseType::String, "");
+    let mut descriptor = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        0,
+        10,
+    )?;
+    if let ValueDescriptorDetails::String(ref mut string) = descriptor.details {
+        string.pattern = Some("([a-z]+".to_string());
+    }
+    Ok(descriptor)
+}
+
+#[fixture]
+pub fn inverted_integer_value_range() -> Result<ValueDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("inverted", BaseType::Integer, "");
+    let mut descriptor = new_integer_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        -10,
+        10,
+    )?;
+    if let ValueDescriptorDetails::Integer(ref mut integer) = descriptor.details {
+        integer.min_value = 11;
+    }
+    Ok(descriptor)
+}
+
+#[fixture]
+pub fn inverted_collection_item_range() -> Result<ValueDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("inverted", BaseType::Collection, "");
+    let mut descriptor = new_value_collection_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        ValueItemType::Dedicated(Box::new(dedicated_string_item()?)),
+        1,
+        5,
+        false,
+        false,
+    )?;
+    if let ValueDescriptorDetails::ValueCollection(ref mut collection) = descriptor.details {
+        collection.min_items = 6;
+    }
+    Ok(descriptor)
+}
+
+/// A collection whose Shared item type has no ActionHash, so it cannot be resolved
+#[fixture]
+pub fn unresolved_collection_item_type() -> Result<ValueDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("unresolved_items", BaseType::Collection, "");
+    let mut descriptor = new_value_collection_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        ValueItemType::Dedicated(Box::new(dedicated_string_item()?)),
+        0,
+        5,
+        false,
+        false,
+    )?;
+    if let ValueDescriptorDetails::ValueCollection(ref mut collection) = descriptor.details {
+        collection.contains_items_of_type = ValueItemType::Shared(HolonReference::new(
+            None,
+            Some(derive_type_name("simple", BaseType::String, "example")),
+        ));
+    }
+    Ok(descriptor)
+}
+
+fn dedicated_string_item() -> Result<ValueDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("item", BaseType::String, "");
+    new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        0,
+        10,
+    )
+}
+
+#[fixture]
+pub fn inverted_range_in_nested_composite() -> Result<ValueDescriptor, DescriptorsError> {
+    let mut inverted_integer = inverted_integer_value_range()?;
+    inverted_integer.header.is_dependent = true;
+    let usage = PropertyDescriptorUsage::new(
+        "an integer property that can never be satisfied".to_string(),
+        inverted_integer,
+        "an inverted property".to_string(),
+        DescriptorSharing::default(),
+    );
+
+    let type_name = derive_type_name("inverted", BaseType::Composite, "");
+    let mut properties = PropertyDescriptorMap::new(BTreeMap::new());
+    upsert_property_descriptor(&mut properties, "an_inverted_property".to_string(), &usage);
+    let mut descriptor = new_composite_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        PropertyDescriptorMap::new(BTreeMap::new()),
+    )?;
+    if let ValueDescriptorDetails::Composite(ref mut composite) = descriptor.details {
+        composite.property_map = properties;
+    }
+    Ok(descriptor)
+}
+
+#[fixture]
+pub fn enum_without_variants() -> Result<ValueDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("empty", BaseType::Enum, "");
+    let mut descriptor = new_enum_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        derive_enum_variants(&["Placeholder"]),
+    )?;
+    if let ValueDescriptorDetails::Enum(ref mut enum_descriptor) = descriptor.details {
+        enum_descriptor.variants.clear();
+    }
+    Ok(descriptor)
+}
+
+#[fixture]
+pub fn enum_with_duplicate_variants() -> Result<ValueDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("duplicated", BaseType::Enum, "");
+    let mut descriptor = new_enum_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        derive_enum_variants(&["Red", "Green"]),
+    )?;
+    if let ValueDescriptorDetails::Enum(ref mut enum_descriptor) = descriptor.details {
+        let mut duplicate = enum_descriptor.variants[0].clone();
+        duplicate.code = Some(99);
+        enum_descriptor.variants.push(duplicate);
+    }
+    Ok(descriptor)
+}
+
+// Invalid updates: each test case pairs a valid original with an update that breaks one of the
+// rules for revising a descriptor. Updates that change identity fields are given a major version
+// bump so that the identity rule is the only one they break.
+
+fn build_immutable_string_descriptor() -> Result<ValueDescriptor, DescriptorsError> {
+    let type_name = derive_type_name("immutable", BaseType::String, "");
+    new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        0,
+        100,
+    )
+}
+
+#[fixture]
+pub fn update_changes_type_name() -> Result<ValueDescriptorTestCase, DescriptorsError> {
+    let original = build_immutable_string_descriptor()?;
+    let mut updated = original.clone();
+    updated.header.type_name = derive_type_name("renamed", BaseType::String, "");
+    updated.header.version = SemanticVersion::new(1, 0, 0);
+    Ok(ValueDescriptorTestCase {
+        original,
+        updates: vec![updated],
+    })
+}
+
+#[fixture]
+pub fn update_changes_base_type() -> Result<ValueDescriptorTestCase, DescriptorsError> {
+    let original = build_immutable_string_descriptor()?;
+    let mut updated = new_integer_descriptor(
+        original.header.type_name.clone(),
+        original.header.description.clone(),
+        original.header.label.clone(),
+        original.header.is_dependent,
+        0,
+        100,
+    )?;
+    updated.header.version = SemanticVersion::new(1, 0, 0);
+    Ok(ValueDescriptorTestCase {
+        original,
+        updates: vec![updated],
+    })
+}
+
+#[fixture]
+pub fn update_changes_is_dependent() -> Result<ValueDescriptorTestCase, DescriptorsError> {
+    let original = build_immutable_string_descriptor()?;
+    let mut updated = original.clone();
+    updated.header.is_dependent = !original.header.is_dependent;
+    updated.header.version = SemanticVersion::new(1, 0, 0);
+    Ok(ValueDescriptorTestCase {
+        original,
+        updates: vec![updated],
+    })
+}
+
+#[fixture]
+pub fn update_without_version_bump() -> Result<ValueDescriptorTestCase, DescriptorsError> {
+    let original = build_immutable_string_descriptor()?;
+    let mut updated = original.clone();
+    updated.header.label = "a new label without a new version".to_string();
+    Ok(ValueDescriptorTestCase {
+        original,
+        updates: vec![updated],
+    })
+}
+
+#[fixture]
+pub fn update_with_insufficient_version_bump() -> Result<ValueDescriptorTestCase, DescriptorsError>
+{
+    let original = build_immutable_string_descriptor()?;
+    // narrowing max_length is a breaking change, so a patch bump is not enough
+    let mut updated = update_string_descriptor(&original, None, None, None, Some(50), None, None)?;
+    updated.header.version = SemanticVersion::new(0, 0, 2);
+    Ok(ValueDescriptorTestCase {
+        original,
+        updates: vec![updated],
+    })
+}
+
+#[fixture]
+pub fn update_adds_property_with_patch_bump() -> Result<ValueDescriptorTestCase, DescriptorsError>
+{
+    let street_type_name = derive_type_name("street", BaseType::String, "");
+    let street = new_string_descriptor(
+        street_type_name.clone(),
+        derive_type_description(&street_type_name),
+        derive_label(&street_type_name),
+        true,
+        0,
+        64,
+    )?;
+    let usage = PropertyDescriptorUsage::new(
+        "a dedicated string property".to_string(),
+        street,
+        "street".to_string(),
+        DescriptorSharing::default(),
+    );
+    let type_name = derive_type_name("growing", BaseType::Composite, "");
+    let mut properties = PropertyDescriptorMap::new(BTreeMap::new());
+    upsert_property_descriptor(&mut properties, "street".to_string(), &usage);
+    let original = new_composite_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        properties,
+    )?;
+    // properties of a composite may be left without a value, so adding one is a minor change
+    let mut updated = original.clone();
+    if let ValueDescriptorDetails::Composite(ref mut composite) = updated.details {
+        upsert_property_descriptor(&mut composite.property_map, "city".to_string(), &usage);
+    }
+    updated.header.version = SemanticVersion::new(0, 0, 2);
+    Ok(ValueDescriptorTestCase {
+        original,
+        updates: vec![updated],
+    })
+}
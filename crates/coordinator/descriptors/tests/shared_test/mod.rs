@@ -2,12 +2,16 @@
 
 pub mod fixture_helpers;
 pub mod holon_descriptor_fixtures;
+pub mod holon_fixtures;
+pub mod invalid_descriptor_fixtures;
 pub mod property_descriptor_data_creators;
 pub mod value_descriptor_fixtures;
 pub mod test_data_types;
 
 use hdk::prelude::*;
-use holochain::sweettest::{SweetAgents, SweetCell, SweetConductor, SweetDnaFile};
+use holochain::sweettest::{
+    SweetAgents, SweetCell, SweetConductor, SweetConductorBatch, SweetDnaFile,
+};
 
 const DNA_FILEPATH: &str = "../../../workdir/map_descriptors.dna";
 
@@ -37,3 +41,21 @@ pub async fn setup_conductor() -> (SweetConductor, AgentPubKey, SweetCell) {
 
     (conductor, agent, cell)
 }
+
+/// MOCK CONDUCTORS
+
+/// Sets up `count` conductors, each running the DNA as a different agent. The conductors don't
+/// know about each other until `exchange_peer_info` is called on the batch, so tests can create
+/// conflicting data on a partitioned network before joining it up.
+#[allow(dead_code)]
+pub async fn setup_conductors(count: usize) -> (SweetConductorBatch, Vec<SweetCell>) {
+    let dna = SweetDnaFile::from_bundle(std::path::Path::new(&DNA_FILEPATH))
+        .await
+        .unwrap();
+
+    let mut conductors = SweetConductorBatch::from_standard_config(count).await;
+    let apps = conductors.setup_app("app", &[dna]).await.unwrap();
+    let cells = apps.cells_flattened();
+
+    (conductors, cells)
+}
@@ -1,13 +1,18 @@
 use descriptors::mutators::{
-    new_boolean_descriptor, new_integer_descriptor, new_string_descriptor,
-    update_boolean_descriptor, update_integer_descriptor, update_string_descriptor,
+    new_boolean_descriptor, new_enum_descriptor, new_integer_descriptor, new_string_descriptor,
+    update_boolean_descriptor, update_enum_descriptor, update_integer_descriptor,
+    update_string_descriptor,
 };
 use descriptors::property_map_builder::upsert_property_descriptor;
 // use hdk::prelude::*;
 use shared_types_descriptor::error::DescriptorsError;
 use shared_types_descriptor::value_descriptor::{DescriptorSharing, PropertyDescriptorMap, PropertyDescriptorUsage};
+use shared_types_descriptor::value_descriptor::{StringFormat, ValueDescriptorDetails};
 use shared_types_descriptor::type_header::BaseType;
-use crate::shared_test::fixture_helpers::{derive_label, derive_type_description, derive_type_name};
+use shared_types_descriptor::versioning::{classify_value_descriptor_change, ChangeKind};
+use crate::shared_test::fixture_helpers::{
+    derive_enum_variants, derive_label, derive_type_description, derive_type_name,
+};
 
 /// This function adds a set of PropertyDescriptors of various Scalar Types to supplied PropertyMap
 ///
@@ -233,6 +238,27 @@ pub fn create_example_property_descriptors(
         &u64_usage,
     );
 
+    // Enum
+    let type_name = derive_type_name("simple", BaseType::Enum, "");
+    let enum_descriptor = new_enum_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        derive_enum_variants(&["Low", "Medium", "High"]),
+    )?;
+    let enum_usage = PropertyDescriptorUsage::new(
+        "example enum property description".to_string(),
+        enum_descriptor,
+        "an enum property".to_string(),
+        DescriptorSharing::default(),
+    );
+    upsert_property_descriptor(
+        property_descriptor_map,
+        "an_enum_property".to_string(),
+        &enum_usage,
+    );
+
     Ok(property_descriptor_map.clone())
 }
 
@@ -264,13 +290,49 @@ pub fn create_example_updates_for_property_descriptors(
     let expected_string_descriptor = property_descriptor_map.properties.get(&property_name);
     if let Some(string_usage) = expected_string_descriptor {
         let mut updated_string_usage = string_usage.clone();
-        updated_string_usage.descriptor = update_string_descriptor(
+        let patterned_descriptor = update_string_descriptor(
             &string_usage.descriptor,
-            Some("changed min".to_string()),
+            Some("changed min, added pattern".to_string()),
             Some("a new label".to_string()),
             Some(3),
             None,
+            Some(Some("^[A-Za-z ]*$".to_string())),
+            None,
+        )?;
+
+        // Setting a format rejects strings that used to be valid
+        let formatted_descriptor = update_string_descriptor(
+            &patterned_descriptor,
+            Some("added format".to_string()),
+            None,
+            None,
+            None,
+            None,
+            Some(Some(StringFormat::Email)),
+        )?;
+        assert_eq!(
+            ChangeKind::Major,
+            classify_value_descriptor_change(&patterned_descriptor, &formatted_descriptor)
+        );
+
+        // Some(None) clears a constraint
+        updated_string_usage.descriptor = update_string_descriptor(
+            &formatted_descriptor,
+            Some("cleared pattern and format".to_string()),
+            None,
+            None,
+            None,
+            Some(None),
+            Some(None),
         )?;
+        if let ValueDescriptorDetails::String(string_details) =
+            &updated_string_usage.descriptor.details
+        {
+            assert_eq!(None, string_details.pattern);
+            assert_eq!(None, string_details.format);
+        } else {
+            panic!("Expected a StringDescriptor, found {:?}", updated_string_usage.descriptor);
+        }
         upsert_property_descriptor(
             property_descriptor_map,
             "a_string_property".to_string(),
@@ -448,5 +510,25 @@ pub fn create_example_updates_for_property_descriptors(
         panic!("Expected {:?}, not found", property_name);
     }
 
+    // Update Enum
+    let property_name = "an_enum_property".to_string();
+    let expected_enum_descriptor = property_descriptor_map.properties.get(&property_name);
+    if let Some(enum_usage) = expected_enum_descriptor {
+        let mut updated_enum_usage = enum_usage.clone();
+        updated_enum_usage.descriptor = update_enum_descriptor(
+            &enum_usage.descriptor,
+            Some("add a variant".to_string()),
+            Some("a new label".to_string()),
+            Some(derive_enum_variants(&["Low", "Medium", "High", "Critical"])),
+        )?;
+        upsert_property_descriptor(
+            property_descriptor_map,
+            "an_enum_property".to_string(),
+            &updated_enum_usage,
+        );
+    } else {
+        panic!("Expected {:?}, not found", property_name);
+    }
+
     Ok(property_descriptor_map.clone())
 }
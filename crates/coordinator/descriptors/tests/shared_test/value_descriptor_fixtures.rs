@@ -15,14 +15,17 @@
 use crate::shared_test::test_data_types::{ValueDescriptorTestCase, SharedTypesTestCase};
 use descriptors::helpers::{get_composite_descriptor_from_details, get_composite_descriptor_map};
 use descriptors::mutators::{
-    new_boolean_descriptor, new_composite_descriptor, new_integer_descriptor, new_string_descriptor,
+    bump_value_descriptor_version, new_boolean_descriptor, new_composite_descriptor,
+    new_enum_descriptor, new_integer_descriptor, new_string_descriptor,
 };
 use descriptors::property_map_builder::upsert_property_descriptor;
 use rstest::*;
 use std::collections::btree_map::BTreeMap;
 
 // use hdk::prelude::*;
-use crate::shared_test::fixture_helpers::{derive_label, derive_type_description, derive_type_name};
+use crate::shared_test::fixture_helpers::{
+    derive_enum_variants, derive_label, derive_type_description, derive_type_name,
+};
 use crate::shared_test::property_descriptor_data_creators::{
     create_example_property_descriptors, create_example_updates_for_property_descriptors,
 };
@@ -73,6 +76,17 @@ pub fn new_dedicated_value_descriptors_fixture() -> Result<Vec<ValueDescriptor>,
     )?;
     test_data_set.push(boolean_descriptor);
 
+    // ----------------  VALUE  DESCRIPTOR WITH ENUM VALUE TYPE -------------------------------
+    let type_name = derive_type_name("simple", BaseType::Enum, "example");
+    let enum_descriptor = new_enum_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        derive_enum_variants(&["Draft", "Published", "Archived"]),
+    )?;
+    test_data_set.push(enum_descriptor);
+
     // ----------------  VALUE  DESCRIPTOR WITH COMPOSITE VALUE TYPE -------------------------------
     let mut composite_properties = PropertyDescriptorMap::new(BTreeMap::new());
     let _unused_result = create_example_property_descriptors(&mut composite_properties)?;
@@ -200,10 +214,11 @@ pub fn update_value_descriptor_composite() -> Result<ValueDescriptorTestCase, De
 
     composite_descriptor.property_map = descriptor_map;
 
-    let updated_descriptor = ValueDescriptor {
+    let mut updated_descriptor = ValueDescriptor {
         header: original_descriptor.header.clone(),
         details: ValueDescriptorDetails::Composite(composite_descriptor),
     };
+    bump_value_descriptor_version(&original_descriptor, &mut updated_descriptor)?;
 
     updates.push(updated_descriptor.clone());
 
@@ -7,14 +7,23 @@ use holochain::sweettest::{SweetCell, SweetConductor};
 use std::collections::BTreeMap;
 
 use descriptors::helpers::*;
+use descriptors::mutators::{
+    new_composite_descriptor, new_integer_descriptor, new_string_descriptor,
+    new_value_collection_descriptor,
+};
+use descriptors::property_map_builder::upsert_property_descriptor;
 use rstest::*;
 
+use shared_test::fixture_helpers::{derive_label, derive_type_description, derive_type_name};
+use shared_test::holon_fixtures::book_holon_descriptor;
 use shared_test::value_descriptor_fixtures::*;
 use shared_test::test_data_types::SharedTypesTestCase;
 use shared_types_descriptor::error::DescriptorsError;
-use shared_types_descriptor::holon_descriptor::HolonReference;
+use shared_types_descriptor::holon_descriptor::{HolonDescriptor, HolonReference};
+use shared_types_descriptor::type_header::BaseType;
 use shared_types_descriptor::value_descriptor::{
-    CompositeDescriptor, DescriptorSharing, ValueDescriptor, ValueDescriptorDetails,
+    CompositeDescriptor, DescriptorSharing, PropertyDescriptorMap, PropertyDescriptorUsage,
+    ValueDescriptor, ValueDescriptorDetails, ValueItemType,
 };
 
 /// To selectively run JUST THE TESTS in this file, use:
@@ -216,3 +225,195 @@ async fn rstest_shared_properties(#[case] input: Result<SharedTypesTestCase, Des
         }
     }
 }
+
+/// This test verifies that a ValueCollectionDescriptor may share its item type with a stored
+/// ValueDescriptor, and that a Shared item type referencing a HolonDescriptor, or naming another
+/// type_name than the one it identifies, is rejected.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_shared_collection_item_types(
+    book_holon_descriptor: Result<HolonDescriptor, DescriptorsError>,
+) {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    let type_name = derive_type_name("keyword", BaseType::String, "");
+    let keyword = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        1,
+        32,
+    )
+    .unwrap();
+    let keyword_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", keyword)
+        .await;
+
+    let type_name = derive_type_name("keywords", BaseType::Collection, "");
+    let keywords = new_value_collection_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        ValueItemType::Shared(HolonReference::new(
+            Some(keyword_record.action_address().clone()),
+            Some(derive_type_name("keyword", BaseType::String, "")),
+        )),
+        0,
+        10,
+        true,
+        false,
+    )
+    .unwrap();
+    let keywords_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", keywords.clone())
+        .await;
+    let fetched_record: Option<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_value_descriptor",
+            keywords_record.action_address().clone(),
+        )
+        .await;
+    let fetched = get_value_descriptor_from_record(fetched_record.unwrap()).unwrap();
+    assert_eq!(keywords, fetched);
+    println!("Success! Collection sharing a stored item type was created");
+
+    let book = book_holon_descriptor.unwrap();
+    let book_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", book.clone())
+        .await;
+    let type_name = derive_type_name("books", BaseType::Collection, "");
+    let books = new_value_collection_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        ValueItemType::Shared(HolonReference::new(
+            Some(book_record.action_address().clone()),
+            Some(book.header.type_name.clone()),
+        )),
+        0,
+        10,
+        false,
+        false,
+    )
+    .unwrap();
+    let result: Result<Record, _> = conductor
+        .call_fallible(&cell.zome("descriptors"), "create_value_descriptor", books)
+        .await;
+    assert!(result.is_err());
+    println!("Success! Collection sharing a HolonDescriptor as its item type was rejected");
+
+    let type_name = derive_type_name("misnamed", BaseType::Collection, "");
+    let misnamed = new_value_collection_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        ValueItemType::Shared(HolonReference::new(
+            Some(keyword_record.action_address().clone()),
+            Some(derive_type_name("tag", BaseType::String, "")),
+        )),
+        0,
+        10,
+        false,
+        false,
+    )
+    .unwrap();
+    let result: Result<Record, _> = conductor
+        .call_fallible(&cell.zome("descriptors"), "create_value_descriptor", misnamed)
+        .await;
+    let error = format!("{:?}", result.unwrap_err());
+    assert!(error.contains("reference names type_name tag_String_Type"), "{error}");
+    println!("Success! Collection naming another type than the one it identifies was rejected");
+}
+
+/// This test verifies that shared usages cannot be forged: each must identify a stored
+/// ValueDescriptor whose type_name and base_type match the usage's embedded copy.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_forged_shared_references(
+    book_holon_descriptor: Result<HolonDescriptor, DescriptorsError>,
+) {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    let type_name = derive_type_name("keyword", BaseType::String, "");
+    let keyword = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        1,
+        32,
+    )
+    .unwrap();
+    let keyword_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", keyword.clone())
+        .await;
+    let keyword_hash = keyword_record.action_address().clone();
+
+    let book = book_holon_descriptor.unwrap();
+    let book_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_holon_descriptor", book.clone())
+        .await;
+
+    let mut renamed_copy = keyword.clone();
+    renamed_copy.header.type_name = derive_type_name("renamed", BaseType::String, "");
+    let type_name = derive_type_name("count", BaseType::Integer, "");
+    let retyped_copy = new_integer_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        0,
+        10,
+    )
+    .unwrap();
+
+    // each forgery, with a fragment of the message it must be rejected with, if it matters
+    let forgeries = [
+        (
+            "not_a_value",
+            keyword.clone(),
+            Some(book_record.action_address().clone()),
+            Some("must be a ValueDescriptor"),
+        ),
+        ("unidentified", keyword.clone(), None, None),
+        ("renamed", renamed_copy, Some(keyword_hash.clone()), None),
+        ("retyped", retyped_copy, Some(keyword_hash.clone()), None),
+    ];
+    for (prefix, embedded, id, expected_message) in forgeries {
+        let usage = PropertyDescriptorUsage::new(
+            "a forged shared property".to_string(),
+            embedded,
+            "keyword".to_string(),
+            DescriptorSharing::Shared(HolonReference::new(
+                id,
+                Some(keyword.header.type_name.clone()),
+            )),
+        );
+        let mut properties = PropertyDescriptorMap::new(BTreeMap::new());
+        upsert_property_descriptor(&mut properties, "keyword".to_string(), &usage);
+        let type_name = derive_type_name(prefix, BaseType::Composite, "");
+        let composite = new_composite_descriptor(
+            type_name.clone(),
+            derive_type_description(&type_name),
+            derive_label(&type_name),
+            false,
+            properties,
+        )
+        .unwrap();
+        let result: Result<Record, _> = conductor
+            .call_fallible(&cell.zome("descriptors"), "create_value_descriptor", composite)
+            .await;
+        let error = format!("{:?}", result.unwrap_err());
+        if let Some(expected_message) = expected_message {
+            assert!(error.contains(expected_message), "{prefix}: {error}");
+        }
+    }
+    println!("Success! Forged shared references were rejected");
+}
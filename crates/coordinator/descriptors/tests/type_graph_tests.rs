@@ -0,0 +1,206 @@
+//! Type Graph Cycle Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+use std::collections::BTreeMap;
+
+use descriptors::mutators::{
+    bump_value_descriptor_version, check_acyclic, new_composite_descriptor,
+    new_string_descriptor, new_value_collection_descriptor,
+};
+use descriptors::property_map_builder::upsert_property_descriptor;
+use descriptors::value_descriptor_storage_fns::UpdateValueDescriptorInput;
+use rstest::*;
+use shared_test::fixture_helpers::{derive_label, derive_type_description, derive_type_name};
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::holon_descriptor::HolonReference;
+use shared_types_descriptor::type_header::BaseType;
+use shared_types_descriptor::value_descriptor::{
+    DescriptorSharing, PropertyDescriptorMap, PropertyDescriptorUsage, ValueDescriptor,
+    ValueDescriptorDetails, ValueItemType,
+};
+
+fn composite(prefix: &str, properties: Vec<(&str, PropertyDescriptorUsage)>) -> ValueDescriptor {
+    let mut property_map = PropertyDescriptorMap::new(BTreeMap::new());
+    for (name, usage) in properties {
+        upsert_property_descriptor(&mut property_map, name.to_string(), &usage);
+    }
+    let type_name = derive_type_name(prefix, BaseType::Composite, "");
+    new_composite_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        property_map,
+    )
+    .unwrap()
+}
+
+fn usage(descriptor: &ValueDescriptor, sharing: DescriptorSharing) -> PropertyDescriptorUsage {
+    PropertyDescriptorUsage::new(
+        format!("a {} property", descriptor.header.type_name),
+        descriptor.clone(),
+        descriptor.header.label.clone(),
+        sharing,
+    )
+}
+
+fn name_property() -> PropertyDescriptorUsage {
+    let type_name = derive_type_name("name", BaseType::String, "");
+    let name = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        1,
+        64,
+    )
+    .unwrap();
+    usage(&name, DescriptorSharing::Dedicated)
+}
+
+fn person_within_address() -> ValueDescriptor {
+    let person = composite("person", vec![("name", name_property())]);
+    let address = composite(
+        "address",
+        vec![("resident", usage(&person, DescriptorSharing::Dedicated))],
+    );
+    composite(
+        "person",
+        vec![
+            ("name", name_property()),
+            ("address", usage(&address, DescriptorSharing::Dedicated)),
+        ],
+    )
+}
+
+// the mutators refuse a Shared item type without an ActionHash, so it is tampered with
+fn collection_of_itself() -> ValueDescriptor {
+    let type_name = derive_type_name("tree", BaseType::Collection, "");
+    let mut tree = new_value_collection_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        false,
+        ValueItemType::Dedicated(Box::new(name_property().descriptor)),
+        0,
+        10,
+        false,
+        false,
+    )
+    .unwrap();
+    if let ValueDescriptorDetails::ValueCollection(ref mut collection) = tree.details {
+        collection.contains_items_of_type =
+            ValueItemType::Shared(HolonReference::new(None, Some(type_name)));
+    }
+    tree
+}
+
+fn cycle(prefixes: &[(&str, BaseType)]) -> Vec<String> {
+    prefixes
+        .iter()
+        .map(|(prefix, base_type)| derive_type_name(prefix, base_type.clone(), ""))
+        .collect()
+}
+
+/// These tests check staged descriptors for cycles through the descriptors embedded in them,
+/// which needs no conductor.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test type_graph_tests -- --show-output
+#[rstest]
+#[case::acyclic_composite(composite("person", vec![("name", name_property())]), Ok(()))]
+#[case::composite_containing_itself(
+    person_within_address(),
+    Err(DescriptorsError::CyclicTypeGraph(cycle(&[
+        ("person", BaseType::Composite),
+        ("address", BaseType::Composite),
+        ("person", BaseType::Composite),
+    ])))
+)]
+#[case::collection_of_itself(
+    collection_of_itself(),
+    Err(DescriptorsError::CyclicTypeGraph(cycle(&[
+        ("tree", BaseType::Collection),
+        ("tree", BaseType::Collection),
+    ])))
+)]
+fn rstest_check_acyclic(
+    #[case] descriptor: ValueDescriptor,
+    #[case] expected: Result<(), DescriptorsError>,
+) {
+    assert_eq!(expected, check_acyclic(&descriptor));
+}
+
+/// This test verifies that the integrity zome rejects a cycle formed through stored shared
+/// descriptors.
+///
+/// Test Outline:
+/// 1. Create an `address` composite, then a `person` composite that shares it.
+/// 2. Attempt to update `address` with a collection of `person` items, and verify it is rejected.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_shared_cycle_is_rejected() {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. person -> address
+    let address = composite("address", vec![("street", name_property())]);
+    let address_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", address.clone())
+        .await;
+    let address_hash = address_record.action_address().clone();
+    let address_reference =
+        HolonReference::new(Some(address_hash.clone()), Some(address.header.type_name.clone()));
+
+    let person = composite(
+        "person",
+        vec![
+            ("name", name_property()),
+            ("address", usage(&address, DescriptorSharing::Shared(address_reference))),
+        ],
+    );
+    let person_record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", person.clone())
+        .await;
+    let person_hash = person_record.action_address().clone();
+
+    // 2. address -> person closes the cycle. The item type names no type_name, so the cycle is
+    // only visible by following the shared references on the DHT.
+    let type_name = derive_type_name("residents", BaseType::Collection, "");
+    let residents = new_value_collection_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        ValueItemType::Shared(HolonReference::new(Some(person_hash), None)),
+        0,
+        10,
+        false,
+        false,
+    )
+    .unwrap();
+    let mut updated_address = address.clone();
+    if let ValueDescriptorDetails::Composite(ref mut details) = updated_address.details {
+        upsert_property_descriptor(
+            &mut details.property_map,
+            "residents".to_string(),
+            &usage(&residents, DescriptorSharing::Dedicated),
+        );
+    }
+    bump_value_descriptor_version(&address, &mut updated_address).unwrap();
+    assert_eq!(Ok(()), check_acyclic(&updated_address));
+
+    let update_input = UpdateValueDescriptorInput {
+        original_value_descriptor_hash: address_hash.clone(),
+        previous_value_descriptor_hash: address_hash,
+        updated_value_descriptor: updated_address,
+    };
+    let result: Result<Record, _> = conductor
+        .call_fallible(&cell.zome("descriptors"), "update_value_descriptor", update_input)
+        .await;
+    assert!(result.is_err());
+    println!("Success! Update closing a cycle of shared descriptors was rejected");
+}
@@ -0,0 +1,212 @@
+//! Type Name Registry Test Cases
+
+mod shared_test;
+
+use hdk::prelude::*;
+use holochain::sweettest::{SweetCell, SweetConductor};
+use holochain::test_utils::consistency_10s;
+
+use descriptors::descriptor_deletion::{DeleteDescriptorInput, DeletionMode};
+use descriptors::helpers::get_value_descriptor_from_record;
+use descriptors::mutators::{new_holon_descriptor, new_string_descriptor};
+use rstest::*;
+use shared_test::value_descriptor_fixtures::new_dedicated_value_descriptors_fixture;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::value_descriptor::ValueDescriptor;
+
+/// This test verifies that a type_name can only be claimed once across the descriptor registry
+/// and that a claimed type_name resolves to its descriptor.
+///
+/// Test Outline:
+/// 1. Create each ValueDescriptor in the fixture, then resolve its type_name with
+///    `get_descriptor_by_type_name` and verify the fetched descriptor matches.
+/// 2. Attempt to create a second ValueDescriptor and a HolonDescriptor reusing that type_name
+///    and verify both creates are rejected.
+/// 3. Verify that an unclaimed type_name resolves to None.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test type_name_registry_tests -- --show-output
+#[rstest]
+#[case::mixture_of_dedicated_value_types(new_dedicated_value_descriptors_fixture())]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_type_name_uniqueness(
+    #[case] input: Result<Vec<ValueDescriptor>, DescriptorsError>,
+) {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    println!("******* STARTING TESTS FOR TYPE NAME REGISTRY *************************** \n");
+
+    for descriptor in input.unwrap() {
+        let type_name = descriptor.header.type_name.clone();
+        let _created_record: Record = conductor
+            .call(
+                &cell.zome("descriptors"),
+                "create_value_descriptor",
+                descriptor.clone(),
+            )
+            .await;
+
+        let fetched_record: Option<Record> = conductor
+            .call(
+                &cell.zome("descriptors"),
+                "get_descriptor_by_type_name",
+                type_name.clone(),
+            )
+            .await;
+        let fetched_descriptor =
+            get_value_descriptor_from_record(fetched_record.unwrap()).unwrap();
+        assert_eq!(descriptor, fetched_descriptor);
+        println!("{type_name} resolves to its descriptor, attempting to claim it again...");
+
+        let mut duplicate = descriptor.clone();
+        duplicate.header.description = "a different descriptor with the same name".to_string();
+        let duplicate_value: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("descriptors"),
+                "create_value_descriptor",
+                duplicate,
+            )
+            .await;
+        assert!(duplicate_value.is_err());
+
+        let duplicate_holon = new_holon_descriptor(
+            type_name.clone(),
+            "a holon type reusing a value type's name".to_string(),
+            "duplicate".to_string(),
+            false,
+        )
+        .unwrap();
+        let duplicate_holon: Result<Record, _> = conductor
+            .call_fallible(
+                &cell.zome("descriptors"),
+                "create_holon_descriptor",
+                duplicate_holon,
+            )
+            .await;
+        assert!(duplicate_holon.is_err());
+        println!("...Success! Duplicate claims on {type_name} were rejected. \n");
+    }
+
+    let unclaimed: Option<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_descriptor_by_type_name",
+            "an_unclaimed_type_name".to_string(),
+        )
+        .await;
+    assert!(unclaimed.is_none());
+}
+
+/// This test verifies that when agents on a partitioned network each create a descriptor with
+/// the same type_name, the name resolves to the earliest claim once the network is joined up.
+///
+/// Test Outline:
+/// 1. On two conductors that don't know about each other, create a ValueDescriptor with the same
+///    type_name, the first conductor going first. Both creates succeed.
+/// 2. Join the conductors into one network and wait for it to become consistent.
+/// 3. Verify that the type_name resolves to the first conductor's descriptor from both of them,
+///    and that neither of them can claim it again.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_type_name_claimed_on_partitioned_network() {
+    let (conductors, cells) = shared_test::setup_conductors(2).await;
+
+    // 1. Claim the same type_name on each side of the partition
+    let descriptor = new_string_descriptor(
+        "partitioned_String_Type".to_string(),
+        "a type_name claimed on both sides of a partition".to_string(),
+        "partitioned".to_string(),
+        false,
+        1,
+        64,
+    )
+    .unwrap();
+    let mut records: Vec<Record> = Vec::new();
+    for (conductor, cell) in conductors.iter().zip(cells.iter()) {
+        let record: Record = conductor
+            .call(&cell.zome("descriptors"), "create_value_descriptor", descriptor.clone())
+            .await;
+        records.push(record);
+    }
+
+    // 2. Join up the network
+    conductors.exchange_peer_info().await;
+    consistency_10s([&cells[0], &cells[1]]).await;
+
+    // 3. The earliest claim wins everywhere
+    for (conductor, cell) in conductors.iter().zip(cells.iter()) {
+        let fetched: Option<Record> = conductor
+            .call(
+                &cell.zome("descriptors"),
+                "get_descriptor_by_type_name",
+                descriptor.header.type_name.clone(),
+            )
+            .await;
+        assert_eq!(Some(records[0].action_address()), fetched.as_ref().map(Record::action_address));
+
+        let again: Result<Record, _> = conductor
+            .call_fallible(&cell.zome("descriptors"), "create_value_descriptor", descriptor.clone())
+            .await;
+        assert!(again.is_err());
+    }
+    println!("Success! Both conductors resolve the type_name to the earliest claim");
+}
+
+/// This test verifies that deleting a descriptor releases its type_name.
+///
+/// Test Outline:
+/// 1. Create a ValueDescriptor, then delete it.
+/// 2. Verify that its type_name no longer resolves.
+/// 3. Create a new ValueDescriptor with the same type_name and verify the name resolves to it.
+#[rstest]
+#[tokio::test(flavor = "multi_thread")]
+async fn rstest_type_name_released_on_delete() {
+    let (conductor, _agent, cell): (SweetConductor, AgentPubKey, SweetCell) =
+        shared_test::setup_conductor().await;
+
+    // 1. Create and delete a descriptor
+    let descriptor = new_string_descriptor(
+        "released_String_Type".to_string(),
+        "a type_name released by deleting its descriptor".to_string(),
+        "released".to_string(),
+        false,
+        1,
+        64,
+    )
+    .unwrap();
+    let record: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", descriptor.clone())
+        .await;
+    let delete_input = DeleteDescriptorInput {
+        original_descriptor_hash: record.action_address().clone(),
+        mode: DeletionMode::Restrict,
+    };
+    let _deleted: Vec<ActionHash> = conductor
+        .call(&cell.zome("descriptors"), "delete_value_descriptor", delete_input)
+        .await;
+
+    // 2. The type_name is no longer claimed
+    let fetched: Option<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_descriptor_by_type_name",
+            descriptor.header.type_name.clone(),
+        )
+        .await;
+    assert!(fetched.is_none());
+
+    // 3. It can be claimed again
+    let recreated: Record = conductor
+        .call(&cell.zome("descriptors"), "create_value_descriptor", descriptor.clone())
+        .await;
+    let fetched: Option<Record> = conductor
+        .call(
+            &cell.zome("descriptors"),
+            "get_descriptor_by_type_name",
+            descriptor.header.type_name.clone(),
+        )
+        .await;
+    assert_eq!(Some(recreated.action_address()), fetched.as_ref().map(Record::action_address));
+    println!("Success! Deleting a descriptor released its type_name");
+}
@@ -46,7 +46,7 @@ async fn rstest_value_descriptor_capabilities(
     let mut descriptors = input.unwrap();
     descriptors.sort_by(|a, b| a.header.type_name.cmp(&b.header.type_name));
     let d_count = descriptors.len();
-    assert_eq!(d_count, 4);
+    assert_eq!(d_count, 5);
 
     println!("Performing get_all_value_descriptors to ensure initial DB state is empty");
     let result: Vec<Record> = conductor
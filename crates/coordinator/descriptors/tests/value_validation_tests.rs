@@ -0,0 +1,256 @@
+//! Value Validation Test Cases
+
+mod shared_test;
+
+use descriptors::mutators::{
+    new_boolean_descriptor, new_composite_descriptor, new_enum_descriptor, new_integer_descriptor,
+    new_string_descriptor, new_value_collection_descriptor, update_string_descriptor,
+};
+use descriptors::property_map_builder::upsert_property_descriptor;
+use rstest::*;
+use std::collections::BTreeMap;
+
+use shared_test::fixture_helpers::{
+    derive_enum_variants, derive_label, derive_type_description, derive_type_name,
+};
+use shared_types_descriptor::base_value::BaseValue;
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::type_header::BaseType;
+use shared_types_descriptor::value_descriptor::{
+    DescriptorSharing, PropertyDescriptorMap, PropertyDescriptorUsage, StringFormat,
+    ValueDescriptor, ValueItemType,
+};
+use shared_types_descriptor::value_validation::{validate_value, ValueViolation};
+
+const PATH: &str = "CompositeTypeProfile";
+
+fn add_property(map: &mut PropertyDescriptorMap, name: &str, descriptor: ValueDescriptor) {
+    let usage = PropertyDescriptorUsage::new(
+        format!("{name} property description"),
+        descriptor,
+        name.to_string(),
+        DescriptorSharing::default(),
+    );
+    upsert_property_descriptor(map, name.to_string(), &usage);
+}
+
+/// Builds a composite descriptor with one property of each kind that has constraints to check.
+#[fixture]
+fn profile_descriptor() -> Result<ValueDescriptor, DescriptorsError> {
+    let mut map = PropertyDescriptorMap::new(BTreeMap::new());
+
+    let type_name = derive_type_name("handle", BaseType::String, "");
+    let handle = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        3,
+        16,
+    )?;
+    let handle = update_string_descriptor(
+        &handle,
+        None,
+        None,
+        None,
+        None,
+        Some(Some("[a-z_]+".to_string())),
+        None,
+    )?;
+    add_property(&mut map, "handle", handle);
+
+    let type_name = derive_type_name("country", BaseType::String, "");
+    let country = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        2,
+        2,
+    )?;
+    let country = update_string_descriptor(
+        &country,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Some(StringFormat::IsoCountryCode)),
+    )?;
+    add_property(&mut map, "country", country);
+
+    let type_name = derive_type_name("age", BaseType::Integer, "");
+    let age = new_integer_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        0,
+        150,
+    )?;
+    add_property(&mut map, "age", age);
+
+    let type_name = derive_type_name("active", BaseType::Boolean, "");
+    let active = new_boolean_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        true,
+    )?;
+    add_property(&mut map, "active", active);
+
+    let type_name = derive_type_name("priority", BaseType::Enum, "");
+    let priority = new_enum_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        derive_enum_variants(&["Low", "Medium", "High"]),
+    )?;
+    add_property(&mut map, "priority", priority);
+
+    let type_name = derive_type_name("tag", BaseType::String, "");
+    let tag = new_string_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        1,
+        20,
+    )?;
+    let type_name = derive_type_name("tags", BaseType::Collection, "");
+    let tags = new_value_collection_descriptor(
+        type_name.clone(),
+        derive_type_description(&type_name),
+        derive_label(&type_name),
+        true,
+        ValueItemType::Dedicated(Box::new(tag)),
+        1,
+        3,
+        true,
+        false,
+    )?;
+    add_property(&mut map, "tags", tags);
+
+    new_composite_descriptor(
+        PATH.to_string(),
+        derive_type_description(PATH),
+        derive_label(PATH),
+        false,
+        map,
+    )
+}
+
+fn profile_value(overrides: Vec<(&str, BaseValue)>) -> BaseValue {
+    let mut values = BTreeMap::from([
+        ("handle".to_string(), BaseValue::String("ada_l".to_string())),
+        ("country".to_string(), BaseValue::String("GB".to_string())),
+        ("age".to_string(), BaseValue::Integer(36)),
+        ("active".to_string(), BaseValue::FuzzyBoolean(80)),
+        ("priority".to_string(), BaseValue::Enum("High".to_string())),
+        (
+            "tags".to_string(),
+            BaseValue::Collection(vec![
+                BaseValue::String("math".to_string()),
+                BaseValue::String("engines".to_string()),
+            ]),
+        ),
+    ]);
+    for (name, value) in overrides {
+        values.insert(name.to_string(), value);
+    }
+    BaseValue::Composite(values)
+}
+
+fn at(property_name: &str) -> String {
+    format!("{PATH}.{property_name}")
+}
+
+/// These tests check values against a ValueDescriptor without a conductor, since value
+/// validation is a pure function of the descriptor and the value.
+///
+/// To execute ONLY the tests in this file, use:
+///      cargo test -p descriptors --test value_validation_tests -- --show-output
+#[rstest]
+#[case::conforming_value(profile_value(vec![]), vec![])]
+#[case::properties_may_be_omitted(BaseValue::Composite(BTreeMap::new()), vec![])]
+#[case::string_too_short(
+    profile_value(vec![("handle", BaseValue::String("al".to_string()))]),
+    vec![ValueViolation::LengthOutOfRange(at("handle"), 2, 3, 16)]
+)]
+#[case::string_does_not_match_pattern(
+    profile_value(vec![("handle", BaseValue::String("Ada-L".to_string()))]),
+    vec![ValueViolation::PatternMismatch(at("handle"), "[a-z_]+".to_string())]
+)]
+#[case::string_does_not_match_format(
+    profile_value(vec![("country", BaseValue::String("gb".to_string()))]),
+    vec![ValueViolation::FormatMismatch(at("country"), StringFormat::IsoCountryCode)]
+)]
+#[case::integer_out_of_range(
+    profile_value(vec![("age", BaseValue::Integer(-1))]),
+    vec![ValueViolation::ValueOutOfRange(at("age"), -1, 0, 150)]
+)]
+#[case::fuzzy_value_out_of_range(
+    profile_value(vec![("active", BaseValue::FuzzyBoolean(101))]),
+    vec![ValueViolation::InvalidFuzzyValue(at("active"), 101, 100)]
+)]
+#[case::unknown_enum_variant(
+    profile_value(vec![("priority", BaseValue::Enum("Urgent".to_string()))]),
+    vec![ValueViolation::UnknownEnumVariant(at("priority"), "Urgent".to_string())]
+)]
+#[case::duplicate_collection_items(
+    profile_value(vec![("tags", BaseValue::Collection(vec![
+        BaseValue::String("math".to_string()),
+        BaseValue::String("math".to_string()),
+    ]))]),
+    vec![ValueViolation::DuplicateItem(at("tags"), 1)]
+)]
+#[case::too_many_collection_items(
+    profile_value(vec![("tags", BaseValue::Collection(vec![
+        BaseValue::String("a".to_string()),
+        BaseValue::String("b".to_string()),
+        BaseValue::String("c".to_string()),
+        BaseValue::String("d".to_string()),
+    ]))]),
+    vec![ValueViolation::ItemCountOutOfRange(at("tags"), 4, 1, 3)]
+)]
+#[case::collection_item_out_of_range(
+    profile_value(vec![("tags", BaseValue::Collection(vec![
+        BaseValue::String("math".to_string()),
+        BaseValue::String("".to_string()),
+    ]))]),
+    vec![ValueViolation::LengthOutOfRange(format!("{}[1]", at("tags")), 0, 1, 20)]
+)]
+#[case::wrong_kind_of_value(
+    profile_value(vec![("age", BaseValue::String("36".to_string()))]),
+    vec![ValueViolation::KindMismatch(at("age"), BaseType::Integer, "String".to_string())]
+)]
+#[case::unknown_property(
+    profile_value(vec![("nickname", BaseValue::String("Ada".to_string()))]),
+    vec![ValueViolation::UnknownProperty(PATH.to_string(), "nickname".to_string())]
+)]
+#[case::every_violation_is_reported(
+    profile_value(vec![
+        ("age", BaseValue::Integer(200)),
+        ("priority", BaseValue::Enum("Urgent".to_string())),
+    ]),
+    vec![
+        ValueViolation::ValueOutOfRange(at("age"), 200, 0, 150),
+        ValueViolation::UnknownEnumVariant(at("priority"), "Urgent".to_string()),
+    ]
+)]
+fn rstest_value_validation(
+    profile_descriptor: Result<ValueDescriptor, DescriptorsError>,
+    #[case] value: BaseValue,
+    #[case] expected: Vec<ValueViolation>,
+) {
+    let descriptor = profile_descriptor.unwrap();
+    let result = validate_value(&descriptor, &value);
+    println!("Validation result: {:#?}", result);
+    if expected.is_empty() {
+        assert_eq!(result, Ok(()));
+    } else {
+        assert_eq!(result, Err(expected));
+    }
+}
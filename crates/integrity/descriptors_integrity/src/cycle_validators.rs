@@ -0,0 +1,82 @@
+use hdi::prelude::*;
+use std::collections::BTreeSet;
+
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+use shared_types_descriptor::validation::{
+    find_cycle, holon_shared_value_references, shared_value_references,
+    validate_acyclic_holon_descriptor, validate_acyclic_value_descriptor, SharedValueReference,
+};
+use shared_types_descriptor::value_descriptor::ValueDescriptor;
+
+/// Verifies that `descriptor` does not (transitively) contain itself, either through the
+/// descriptors embedded within it or through the stored descriptors it shares
+pub fn validate_acyclic_value_type(
+    descriptor: &ValueDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Err(e) = validate_acyclic_value_descriptor(descriptor) {
+        return e.into();
+    }
+    validate_acyclic_shared_references(
+        &descriptor.header.type_name,
+        shared_value_references(descriptor),
+    )
+}
+
+pub fn validate_acyclic_holon_type(
+    descriptor: &HolonDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Err(e) = validate_acyclic_holon_descriptor(descriptor) {
+        return e.into();
+    }
+    validate_acyclic_shared_references(
+        &descriptor.header.type_name,
+        holon_shared_value_references(descriptor),
+    )
+}
+
+fn validate_acyclic_shared_references(
+    type_name: &str,
+    references: Vec<SharedValueReference>,
+) -> ExternResult<ValidateCallbackResult> {
+    let mut type_names = vec![type_name.to_string()];
+    let mut visited: BTreeSet<ActionHash> = BTreeSet::new();
+    match find_shared_cycle(references, &mut type_names, &mut visited)? {
+        Some(cycle) => DescriptorsError::CyclicTypeGraph(cycle).into(),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+
+// Depth-first walk of the stored descriptors reachable through `references`. Each stored
+// descriptor is only descended into once, so shared diamonds don't multiply the work.
+// References that can't be resolved are skipped: the reference validators report those.
+fn find_shared_cycle(
+    references: Vec<SharedValueReference>,
+    type_names: &mut Vec<String>,
+    visited: &mut BTreeSet<ActionHash>,
+) -> ExternResult<Option<Vec<String>>> {
+    for shared in references {
+        let action_hash = match shared.reference.id {
+            Some(action_hash) => action_hash,
+            None => continue,
+        };
+        let record = must_get_valid_record(action_hash.clone())?;
+        let stored: ValueDescriptor = match record.entry().to_app_option() {
+            Ok(Some(stored)) => stored,
+            _ => continue,
+        };
+        if let Some(cycle) = find_cycle(type_names, &stored.header.type_name) {
+            return Ok(Some(cycle));
+        }
+        if !visited.insert(action_hash) {
+            continue;
+        }
+        type_names.push(stored.header.type_name.clone());
+        let cycle = find_shared_cycle(shared_value_references(&stored), type_names, visited)?;
+        type_names.pop();
+        if cycle.is_some() {
+            return Ok(cycle);
+        }
+    }
+    Ok(None)
+}
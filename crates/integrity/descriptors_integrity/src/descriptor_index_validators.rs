@@ -0,0 +1,150 @@
+use hdi::hash_path::path::{Component, Path};
+use hdi::prelude::*;
+use shared_types_descriptor::type_header::{tokenize, BaseType, TypeHeader};
+
+use crate::entry_types_from_record;
+use crate::link_deletion_validators::{is_preceded_by_delete_of, is_preceded_by_revision_of};
+
+pub const BASE_TYPES_PATH: &str = "base_types";
+pub const SEARCH_TOKENS_PATH: &str = "search_tokens";
+// the number of leading characters of a search token that select its shard
+const SEARCH_TOKEN_SHARD_LENGTH: usize = 2;
+
+/// Descriptors are indexed for lookup by two kinds of link, both targeting the original
+/// ActionHash of the descriptor:
+/// - DescriptorsOfBaseType links, from the path returned by `base_type_path` for the
+///   descriptor's (immutable) base_type.
+/// - DescriptorsByToken links, one per search token of the descriptor's label and description
+///   (see `TypeHeader::search_tokens`), tagged with the token and anchored at the path returned
+///   by `search_token_path`. Tokens sharing their leading characters share a path, so a query
+///   token can be matched as a prefix of the link tags.
+///
+/// As with the type_name registry, the paths are derived here so that the coordinator and the
+/// validation rules always agree on them.
+pub fn base_type_path(base_type: &BaseType) -> Path {
+    Path::from(vec![
+        Component::from(BASE_TYPES_PATH),
+        Component::from(base_type.to_string()),
+    ])
+}
+
+pub fn search_token_path(token: &str) -> Path {
+    let shard: String = token.chars().take(SEARCH_TOKEN_SHARD_LENGTH).collect();
+    Path::from(vec![
+        Component::from(SEARCH_TOKENS_PATH),
+        Component::from(shard),
+    ])
+}
+
+// Returns the header of the descriptor created by the original Create `target_address`
+fn must_get_original_header(
+    target_address: AnyLinkableHash,
+    link_type_name: &str,
+) -> ExternResult<Result<TypeHeader, ValidateCallbackResult>> {
+    let record = must_get_valid_record(ActionHash::from(target_address))?;
+    if record.action().action_type() != ActionType::Create {
+        return Ok(Err(ValidateCallbackResult::Invalid(format!(
+            "{link_type_name} links must target the original Create of a descriptor"
+        ))));
+    }
+    match entry_types_from_record(&record)?.and_then(|e| e.type_header().cloned()) {
+        Some(header) => Ok(Ok(header)),
+        None => Ok(Err(ValidateCallbackResult::Invalid(format!(
+            "{link_type_name} links must target a descriptor"
+        )))),
+    }
+}
+
+pub fn validate_create_link_descriptors_of_base_type(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let header = match must_get_original_header(target_address, "DescriptorsOfBaseType")? {
+        Ok(header) => header,
+        Err(invalid) => {
+            return Ok(invalid);
+        }
+    };
+    let expected_base = base_type_path(&header.base_type).path_entry_hash()?;
+    if base_address != AnyLinkableHash::from(expected_base) {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "DescriptorsOfBaseType link for {} is not anchored at the path for its base_type",
+            header.type_name
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// The base_type of a descriptor never changes, so its DescriptorsOfBaseType link may only be
+/// deleted by whoever deleted the descriptor, once they have
+pub fn validate_delete_link_descriptors_of_base_type(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if !is_preceded_by_delete_of(&action, &ActionHash::from(target))? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "DescriptorsOfBaseType links can only be deleted after the descriptor they target",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_descriptors_by_token(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Err(invalid) = must_get_original_header(target_address, "DescriptorsByToken")? {
+        return Ok(invalid);
+    }
+    // the token must be a single, normalized search token
+    let token = match String::from_utf8(tag.0) {
+        Ok(token) if tokenize(&token).into_iter().eq([token.clone()]) => token,
+        _ => {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "DescriptorsByToken links must be tagged with a search token",
+            )));
+        }
+    };
+    let expected_base = search_token_path(&token).path_entry_hash()?;
+    if base_address != AnyLinkableHash::from(expected_base) {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "DescriptorsByToken link for {token} is not anchored at the path for that token"
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// A DescriptorsByToken link may be deleted by whoever deleted the descriptor, or revised its
+/// label or description so that they no longer yield the token
+pub fn validate_delete_link_descriptors_by_token(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let original_hash = ActionHash::from(target);
+    if is_preceded_by_delete_of(&action, &original_hash)? {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let token = String::from_utf8_lossy(&tag.0).to_string();
+    let dropped = is_preceded_by_revision_of(&action, &original_hash, |revision| {
+        revision
+            .type_header()
+            .map_or(false, |header| !header.search_tokens().contains(&token))
+    })?;
+    if !dropped {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "DescriptorsByToken links can only be deleted after their descriptor is deleted or \
+             revised to no longer yield the token",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
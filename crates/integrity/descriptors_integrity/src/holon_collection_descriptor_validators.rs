@@ -0,0 +1,150 @@
+use hdi::prelude::*;
+
+use shared_types_descriptor::holon_descriptor::HolonCollectionDescriptor;
+use shared_types_descriptor::validation::{
+    validate_header_identity, validate_holon_collection_descriptor,
+};
+use shared_types_descriptor::versioning::{
+    classify_holon_collection_descriptor_change, validate_version_bump,
+};
+
+use crate::listing_validators::{
+    validate_delete_listing_link, validate_single_path_listing_link,
+    ALL_HOLON_COLLECTION_TYPES_PATH,
+};
+use crate::reference_validators::validate_holon_type_exists;
+use crate::revision_validators::validate_revision_ancestry;
+use crate::type_name_validators::validate_type_name_claimed;
+
+fn validate_item_type(
+    holon_collection_descriptor: &HolonCollectionDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_holon_type_exists(
+        "The contains_items_of_type of a HolonCollectionDescriptor",
+        &holon_collection_descriptor.contains_items_of_type,
+    )
+}
+
+pub fn validate_create_holon_collection_descriptor(
+    action: EntryCreationAction,
+    holon_collection_descriptor: HolonCollectionDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Err(e) = validate_holon_collection_descriptor(&holon_collection_descriptor) {
+        return e.into();
+    }
+    let result = validate_type_name_claimed(&action, &holon_collection_descriptor.header)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    validate_item_type(&holon_collection_descriptor)
+}
+pub fn validate_update_holon_collection_descriptor(
+    _action: Update,
+    holon_collection_descriptor: HolonCollectionDescriptor,
+    _original_action: EntryCreationAction,
+    original_holon_collection_descriptor: HolonCollectionDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Err(e) = validate_holon_collection_descriptor(&holon_collection_descriptor) {
+        return e.into();
+    }
+    if let Err(e) = validate_header_identity(
+        &original_holon_collection_descriptor.header,
+        &holon_collection_descriptor.header,
+    ) {
+        return e.into();
+    }
+    let change = classify_holon_collection_descriptor_change(
+        &original_holon_collection_descriptor,
+        &holon_collection_descriptor,
+    );
+    if let Err(e) = validate_version_bump(
+        &original_holon_collection_descriptor.header.version,
+        &holon_collection_descriptor.header.version,
+        change,
+    ) {
+        return e.into();
+    }
+    validate_item_type(&holon_collection_descriptor)
+}
+pub fn validate_delete_holon_collection_descriptor(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_holon_collection_descriptor: HolonCollectionDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_holon_collection_descriptor_updates(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let original_hash = ActionHash::from(base_address);
+    let record = must_get_valid_record(original_hash.clone())?;
+    let _holon_collection_descriptor: HolonCollectionDescriptor = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let _holon_collection_descriptor: HolonCollectionDescriptor = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    validate_revision_ancestry(&original_hash, &record)
+}
+pub fn validate_delete_link_holon_collection_descriptor_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("HolonCollectionDescriptorUpdates links cannot be deleted"),
+        ),
+    )
+}
+pub fn validate_create_link_all_holon_collection_types(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let _holon_collection_descriptor: HolonCollectionDescriptor = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    validate_single_path_listing_link(
+        "AllHolonCollectionTypes",
+        ALL_HOLON_COLLECTION_TYPES_PATH,
+        base_address,
+    )
+}
+pub fn validate_delete_link_all_holon_collection_types(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_delete_listing_link("AllHolonCollectionTypes", &action, target)
+}
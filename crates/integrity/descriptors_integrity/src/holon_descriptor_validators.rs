@@ -2,21 +2,64 @@ use hdi::prelude::*;
 
 //use crate::EntryTypes::HolonDescriptor;
 use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+use shared_types_descriptor::validation::{validate_header_identity, validate_holon_descriptor};
+use shared_types_descriptor::versioning::{classify_holon_descriptor_change, validate_version_bump};
+
+use crate::cycle_validators::validate_acyclic_holon_type;
+use crate::listing_validators::{
+    validate_delete_listing_link, validate_listing_link, ALL_HOLON_TYPES_PATH,
+};
+use crate::reference_validators::validate_holon_shared_value_references;
+use crate::revision_validators::validate_revision_ancestry;
+use crate::type_name_validators::validate_type_name_claimed;
 
 pub fn validate_create_holon_descriptor(
-    _action: EntryCreationAction,
-    _holon_descriptor: HolonDescriptor,
+    action: EntryCreationAction,
+    holon_descriptor: HolonDescriptor,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    if let Err(e) = validate_holon_descriptor(&holon_descriptor) {
+        return e.into();
+    }
+    let result = validate_type_name_claimed(&action, &holon_descriptor.header)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    let result = validate_holon_shared_value_references(&holon_descriptor)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    validate_acyclic_holon_type(&holon_descriptor)
 }
 pub fn validate_update_holon_descriptor(
     _action: Update,
-    _holon_descriptor: HolonDescriptor,
+    holon_descriptor: HolonDescriptor,
     _original_action: EntryCreationAction,
-    _original_holon_descriptor: HolonDescriptor,
+    original_holon_descriptor: HolonDescriptor,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    if let Err(e) = validate_holon_descriptor(&holon_descriptor) {
+        return e.into();
+    }
+    if let Err(e) =
+        validate_header_identity(&original_holon_descriptor.header, &holon_descriptor.header)
+    {
+        return e.into();
+    }
+    let change = classify_holon_descriptor_change(&original_holon_descriptor, &holon_descriptor);
+    if let Err(e) = validate_version_bump(
+        &original_holon_descriptor.header.version,
+        &holon_descriptor.header.version,
+        change,
+    ) {
+        return e.into();
+    }
+    let result = validate_holon_shared_value_references(&holon_descriptor)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    validate_acyclic_holon_type(&holon_descriptor)
 }
+/// As with ValueDescriptors, the guard against deleting a HolonDescriptor that relationships or
+/// holon collections still reference is advisory, applied by the coordinator rather than here.
 pub fn validate_delete_holon_descriptor(
     _action: Delete,
     _original_action: EntryCreationAction,
@@ -30,8 +73,8 @@ pub fn validate_create_link_holon_descriptor_updates(
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::from(base_address);
-    let record = must_get_valid_record(action_hash)?;
+    let original_hash = ActionHash::from(base_address);
+    let record = must_get_valid_record(original_hash.clone())?;
     let _holon_descriptor: HolonDescriptor = record
         .entry()
         .to_app_option()
@@ -52,7 +95,7 @@ pub fn validate_create_link_holon_descriptor_updates(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
-    Ok(ValidateCallbackResult::Valid)
+    validate_revision_ancestry(&original_hash, &record)
 }
 pub fn validate_delete_link_holon_descriptor_updates(
     _action: DeleteLink,
@@ -69,13 +112,13 @@ pub fn validate_delete_link_holon_descriptor_updates(
 }
 pub fn validate_create_link_all_holon_types(
     _action: CreateLink,
-    _base_address: AnyLinkableHash,
+    base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
-    _tag: LinkTag,
+    tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
     let action_hash = ActionHash::from(target_address);
     let record = must_get_valid_record(action_hash)?;
-    let _holon_descriptor: HolonDescriptor = record
+    let holon_descriptor: HolonDescriptor = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -84,18 +127,20 @@ pub fn validate_create_link_all_holon_types(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
-    Ok(ValidateCallbackResult::Valid)
+    validate_listing_link(
+        "AllHolonTypes",
+        ALL_HOLON_TYPES_PATH,
+        base_address,
+        &holon_descriptor.header,
+        tag,
+    )
 }
 pub fn validate_delete_link_all_holon_types(
-    _action: DeleteLink,
+    action: DeleteLink,
     _original_action: CreateLink,
     _base: AnyLinkableHash,
-    _target: AnyLinkableHash,
+    target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(
-        ValidateCallbackResult::Invalid(
-            String::from("AllHolonTypes links cannot be deleted"),
-        ),
-    )
-}
\ No newline at end of file
+    validate_delete_listing_link("AllHolonTypes", &action, target)
+}
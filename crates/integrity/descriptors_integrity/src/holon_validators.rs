@@ -0,0 +1,114 @@
+use hdi::prelude::*;
+
+use shared_types_descriptor::error::DescriptorsError;
+use shared_types_descriptor::holon::Holon;
+use shared_types_descriptor::holon_descriptor::HolonDescriptor;
+use shared_types_descriptor::value_validation::validate_holon_property_values;
+
+use crate::link_deletion_validators::is_preceded_by_delete_of;
+use crate::revision_validators::validate_revision_ancestry;
+
+/// A holon is valid if the descriptor revision it names is a revision of the HolonDescriptor
+/// it names, carries the version it names, and its property values conform to that revision.
+fn validate_holon_against_descriptor(holon: &Holon) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(holon.descriptor_revision.clone())?;
+    let holon_descriptor: HolonDescriptor = match record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+    {
+        Some(holon_descriptor) => holon_descriptor,
+        None => {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "A Holon must reference a HolonDescriptor",
+            )));
+        }
+    };
+    if holon.descriptor_revision != holon.descriptor {
+        let ancestry = validate_revision_ancestry(&holon.descriptor, &record)?;
+        if !matches!(ancestry, ValidateCallbackResult::Valid) {
+            return Ok(ancestry);
+        }
+    }
+    if holon_descriptor.header.version != holon.descriptor_version {
+        return DescriptorsError::DescriptorVersionMismatch(
+            holon.descriptor_revision.clone(),
+            holon_descriptor.header.version,
+            holon.descriptor_version.clone(),
+        )
+        .into();
+    }
+    if let Err(violations) =
+        validate_holon_property_values(&holon_descriptor, &holon.property_map)
+    {
+        return DescriptorsError::NonConformingValue(violations).into();
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_holon(
+    _action: EntryCreationAction,
+    holon: Holon,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_holon_against_descriptor(&holon)
+}
+pub fn validate_update_holon(
+    _action: Update,
+    holon: Holon,
+    _original_action: EntryCreationAction,
+    original_holon: Holon,
+) -> ExternResult<ValidateCallbackResult> {
+    if holon.descriptor != original_holon.descriptor {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "An update cannot change the HolonDescriptor a Holon is an instance of",
+        )));
+    }
+    validate_holon_against_descriptor(&holon)
+}
+pub fn validate_delete_holon(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_holon: Holon,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_holons_of_type(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let holon: Holon = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    if base_address != AnyLinkableHash::from(holon.descriptor) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "HolonsOfType links must be based at the HolonDescriptor of their target",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Anyone may delete a holon, so its HolonsOfType link may be deleted by whoever deleted the
+/// holon, and only once they have.
+pub fn validate_delete_link_holons_of_type(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if !is_preceded_by_delete_of(&action, &ActionHash::from(target))? {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "HolonsOfType links can only be deleted after the Holon they target",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
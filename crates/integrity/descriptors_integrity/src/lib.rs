@@ -1,7 +1,24 @@
+pub mod cycle_validators;
+pub mod descriptor_index_validators;
+pub mod holon_collection_descriptor_validators;
 pub mod holon_descriptor_validators;
+pub mod holon_validators;
+pub mod link_deletion_validators;
+pub mod listing_validators;
+pub mod reference_validators;
+pub mod relationship_descriptor_validators;
+pub mod revision_validators;
+pub mod source_chain_validators;
+pub mod type_name_validators;
+pub mod used_by_validators;
 pub mod value_descriptor_validators;
 
-use shared_types_descriptor::holon_descriptor::{HolonDescriptor};
+use shared_types_descriptor::holon::Holon;
+use shared_types_descriptor::holon_descriptor::{HolonCollectionDescriptor, HolonDescriptor};
+use shared_types_descriptor::relationship_descriptor::RelationshipDescriptor;
+use shared_types_descriptor::type_descriptor::TypeDescriptor;
+use shared_types_descriptor::type_header::TypeHeader;
+use shared_types_descriptor::type_name_claim::TypeNameClaim;
 use shared_types_descriptor::value_descriptor::{ValueDescriptor};
 use crate::holon_descriptor_validators::{
     validate_create_link_holon_descriptor_updates,
@@ -22,6 +39,43 @@ use crate::value_descriptor_validators::{
     validate_delete_link_value_descriptor_updates,
     validate_delete_value_descriptor,
     validate_update_value_descriptor};
+use crate::holon_collection_descriptor_validators::{
+    validate_create_holon_collection_descriptor,
+    validate_create_link_all_holon_collection_types,
+    validate_create_link_holon_collection_descriptor_updates,
+    validate_delete_holon_collection_descriptor,
+    validate_delete_link_all_holon_collection_types,
+    validate_delete_link_holon_collection_descriptor_updates,
+    validate_update_holon_collection_descriptor,
+};
+use crate::holon_validators::{
+    validate_create_holon,
+    validate_create_link_holons_of_type,
+    validate_delete_holon,
+    validate_delete_link_holons_of_type,
+    validate_update_holon,
+};
+use crate::relationship_descriptor_validators::{
+    validate_create_link_all_relationship_types,
+    validate_create_link_relationship_descriptor_updates,
+    validate_create_relationship_descriptor,
+    validate_delete_link_all_relationship_types,
+    validate_delete_link_relationship_descriptor_updates,
+    validate_delete_relationship_descriptor,
+    validate_update_relationship_descriptor,
+};
+use crate::descriptor_index_validators::{
+    validate_create_link_descriptors_by_token, validate_create_link_descriptors_of_base_type,
+    validate_delete_link_descriptors_by_token, validate_delete_link_descriptors_of_base_type,
+};
+use crate::used_by_validators::{validate_create_link_used_by, validate_delete_link_used_by};
+use crate::type_name_validators::{
+    validate_create_link_type_name_claims,
+    validate_create_type_name_claim,
+    validate_delete_link_type_name_claims,
+    validate_delete_type_name_claim,
+    validate_update_type_name_claim,
+};
 
 #[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -30,6 +84,38 @@ use crate::value_descriptor_validators::{
 pub enum EntryTypes {
     HolonDescriptor(HolonDescriptor),
     ValueDescriptor(ValueDescriptor),
+    Holon(Holon),
+    RelationshipDescriptor(RelationshipDescriptor),
+    HolonCollectionDescriptor(HolonCollectionDescriptor),
+    TypeNameClaim(TypeNameClaim),
+}
+
+impl EntryTypes {
+    /// Returns the TypeHeader of entries that are descriptors
+    pub fn type_header(&self) -> Option<&TypeHeader> {
+        match self {
+            EntryTypes::HolonDescriptor(descriptor) => Some(&descriptor.header),
+            EntryTypes::ValueDescriptor(descriptor) => Some(&descriptor.header),
+            EntryTypes::RelationshipDescriptor(descriptor) => Some(&descriptor.header),
+            EntryTypes::HolonCollectionDescriptor(descriptor) => Some(&descriptor.header),
+            EntryTypes::Holon(_) | EntryTypes::TypeNameClaim(_) => None,
+        }
+    }
+
+    /// Converts entries that are descriptors into a TypeDescriptor
+    pub fn into_type_descriptor(self) -> Option<TypeDescriptor> {
+        match self {
+            EntryTypes::HolonDescriptor(descriptor) => Some(TypeDescriptor::Holon(descriptor)),
+            EntryTypes::ValueDescriptor(descriptor) => Some(TypeDescriptor::Value(descriptor)),
+            EntryTypes::RelationshipDescriptor(descriptor) => {
+                Some(TypeDescriptor::Relationship(descriptor))
+            }
+            EntryTypes::HolonCollectionDescriptor(descriptor) => {
+                Some(TypeDescriptor::HolonCollection(descriptor))
+            }
+            EntryTypes::Holon(_) | EntryTypes::TypeNameClaim(_) => None,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -39,6 +125,37 @@ pub enum LinkTypes {
     AllHolonTypes,
     ValueDescriptorUpdates,
     AllValueDescriptors,
+    TypeNameClaims,
+    HolonsOfType,
+    RelationshipDescriptorUpdates,
+    AllRelationshipTypes,
+    HolonCollectionDescriptorUpdates,
+    AllHolonCollectionTypes,
+    UsedBy,
+    DescriptorsOfBaseType,
+    DescriptorsByToken,
+}
+
+/// Decodes the app entry carried by `record` into one of this zome's EntryTypes.
+/// Returns None if the record has no entry or the entry belongs to some other zome.
+pub fn entry_types_from_record(record: &Record) -> ExternResult<Option<EntryTypes>> {
+    let entry = match record.entry().as_option() {
+        Some(entry) => entry,
+        None => {
+            return Ok(None);
+        }
+    };
+    let app_entry_def = match record.action().entry_type() {
+        Some(EntryType::App(app_entry_def)) => app_entry_def,
+        _ => {
+            return Ok(None);
+        }
+    };
+    EntryTypes::deserialize_from_type(
+        app_entry_def.zome_index.clone(),
+        app_entry_def.entry_index.clone(),
+        entry,
+    )
 }
 
 #[hdk_extern]
@@ -74,6 +191,27 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 value_descriptor,
                             )
                         }
+                        EntryTypes::Holon(holon) => {
+                            validate_create_holon(EntryCreationAction::Create(action), holon)
+                        }
+                        EntryTypes::RelationshipDescriptor(relationship_descriptor) => {
+                            validate_create_relationship_descriptor(
+                                EntryCreationAction::Create(action),
+                                relationship_descriptor,
+                            )
+                        }
+                        EntryTypes::HolonCollectionDescriptor(holon_collection_descriptor) => {
+                            validate_create_holon_collection_descriptor(
+                                EntryCreationAction::Create(action),
+                                holon_collection_descriptor,
+                            )
+                        }
+                        EntryTypes::TypeNameClaim(type_name_claim) => {
+                            validate_create_type_name_claim(
+                                EntryCreationAction::Create(action),
+                                type_name_claim,
+                            )
+                        }
                     }
                 }
                 OpEntry::UpdateEntry { app_entry, action, .. } => {
@@ -90,6 +228,27 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 value_descriptor,
                             )
                         }
+                        EntryTypes::Holon(holon) => {
+                            validate_create_holon(EntryCreationAction::Update(action), holon)
+                        }
+                        EntryTypes::RelationshipDescriptor(relationship_descriptor) => {
+                            validate_create_relationship_descriptor(
+                                EntryCreationAction::Update(action),
+                                relationship_descriptor,
+                            )
+                        }
+                        EntryTypes::HolonCollectionDescriptor(holon_collection_descriptor) => {
+                            validate_create_holon_collection_descriptor(
+                                EntryCreationAction::Update(action),
+                                holon_collection_descriptor,
+                            )
+                        }
+                        EntryTypes::TypeNameClaim(type_name_claim) => {
+                            validate_create_type_name_claim(
+                                EntryCreationAction::Update(action),
+                                type_name_claim,
+                            )
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
@@ -126,6 +285,44 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_holon_descriptor,
                             )
                         }
+                        (EntryTypes::Holon(holon), EntryTypes::Holon(original_holon)) => {
+                            validate_update_holon(action, holon, original_action, original_holon)
+                        }
+                        (
+                            EntryTypes::RelationshipDescriptor(relationship_descriptor),
+                            EntryTypes::RelationshipDescriptor(original_relationship_descriptor),
+                        ) => {
+                            validate_update_relationship_descriptor(
+                                action,
+                                relationship_descriptor,
+                                original_action,
+                                original_relationship_descriptor,
+                            )
+                        }
+                        (
+                            EntryTypes::HolonCollectionDescriptor(holon_collection_descriptor),
+                            EntryTypes::HolonCollectionDescriptor(
+                                original_holon_collection_descriptor,
+                            ),
+                        ) => {
+                            validate_update_holon_collection_descriptor(
+                                action,
+                                holon_collection_descriptor,
+                                original_action,
+                                original_holon_collection_descriptor,
+                            )
+                        }
+                        (
+                            EntryTypes::TypeNameClaim(type_name_claim),
+                            EntryTypes::TypeNameClaim(original_type_name_claim),
+                        ) => {
+                            validate_update_type_name_claim(
+                                action,
+                                type_name_claim,
+                                original_action,
+                                original_type_name_claim,
+                            )
+                        }
                         _ => {
                             Ok(
                                 ValidateCallbackResult::Invalid(
@@ -157,6 +354,30 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 value_descriptor,
                             )
                         }
+                        EntryTypes::Holon(holon) => {
+                            validate_delete_holon(action, original_action, holon)
+                        }
+                        EntryTypes::RelationshipDescriptor(relationship_descriptor) => {
+                            validate_delete_relationship_descriptor(
+                                action,
+                                original_action,
+                                relationship_descriptor,
+                            )
+                        }
+                        EntryTypes::HolonCollectionDescriptor(holon_collection_descriptor) => {
+                            validate_delete_holon_collection_descriptor(
+                                action,
+                                original_action,
+                                holon_collection_descriptor,
+                            )
+                        }
+                        EntryTypes::TypeNameClaim(type_name_claim) => {
+                            validate_delete_type_name_claim(
+                                action,
+                                original_action,
+                                type_name_claim,
+                            )
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
@@ -202,6 +423,73 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
+                LinkTypes::TypeNameClaims => {
+                    validate_create_link_type_name_claims(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::HolonsOfType => {
+                    validate_create_link_holons_of_type(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::RelationshipDescriptorUpdates => {
+                    validate_create_link_relationship_descriptor_updates(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllRelationshipTypes => {
+                    validate_create_link_all_relationship_types(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::HolonCollectionDescriptorUpdates => {
+                    validate_create_link_holon_collection_descriptor_updates(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllHolonCollectionTypes => {
+                    validate_create_link_all_holon_collection_types(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::UsedBy => {
+                    validate_create_link_used_by(action, base_address, target_address, tag)
+                }
+                LinkTypes::DescriptorsOfBaseType => {
+                    validate_create_link_descriptors_of_base_type(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DescriptorsByToken => {
+                    validate_create_link_descriptors_by_token(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
             }
         }
         FlatOp::RegisterDeleteLink {
@@ -249,6 +537,87 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
+                LinkTypes::TypeNameClaims => {
+                    validate_delete_link_type_name_claims(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::HolonsOfType => {
+                    validate_delete_link_holons_of_type(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::RelationshipDescriptorUpdates => {
+                    validate_delete_link_relationship_descriptor_updates(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllRelationshipTypes => {
+                    validate_delete_link_all_relationship_types(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::HolonCollectionDescriptorUpdates => {
+                    validate_delete_link_holon_collection_descriptor_updates(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllHolonCollectionTypes => {
+                    validate_delete_link_all_holon_collection_types(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::UsedBy => {
+                    validate_delete_link_used_by(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DescriptorsOfBaseType => {
+                    validate_delete_link_descriptors_of_base_type(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DescriptorsByToken => {
+                    validate_delete_link_descriptors_by_token(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
             }
         }
         FlatOp::StoreRecord(store_record) => {
@@ -267,6 +636,27 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 value_descriptor,
                             )
                         }
+                        EntryTypes::Holon(holon) => {
+                            validate_create_holon(EntryCreationAction::Create(action), holon)
+                        }
+                        EntryTypes::RelationshipDescriptor(relationship_descriptor) => {
+                            validate_create_relationship_descriptor(
+                                EntryCreationAction::Create(action),
+                                relationship_descriptor,
+                            )
+                        }
+                        EntryTypes::HolonCollectionDescriptor(holon_collection_descriptor) => {
+                            validate_create_holon_collection_descriptor(
+                                EntryCreationAction::Create(action),
+                                holon_collection_descriptor,
+                            )
+                        }
+                        EntryTypes::TypeNameClaim(type_name_claim) => {
+                            validate_create_type_name_claim(
+                                EntryCreationAction::Create(action),
+                                type_name_claim,
+                            )
+                        }
                     }
                 }
                 OpRecord::UpdateEntry {
@@ -354,6 +744,130 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 Ok(result)
                             }
                         }
+                        EntryTypes::Holon(holon) => {
+                            let result = validate_create_holon(
+                                EntryCreationAction::Update(action.clone()),
+                                holon.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_holon: Option<Holon> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_holon = match original_holon {
+                                    Some(holon) => holon,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_holon(
+                                    action,
+                                    holon,
+                                    original_action,
+                                    original_holon,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::RelationshipDescriptor(relationship_descriptor) => {
+                            let result = validate_create_relationship_descriptor(
+                                EntryCreationAction::Update(action.clone()),
+                                relationship_descriptor.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_relationship_descriptor: Option<
+                                    RelationshipDescriptor,
+                                > = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_relationship_descriptor =
+                                    match original_relationship_descriptor {
+                                        Some(relationship_descriptor) => relationship_descriptor,
+                                        None => {
+                                            return Ok(
+                                                ValidateCallbackResult::Invalid(
+                                                    "The updated entry type must be the same as the original entry type"
+                                                        .to_string(),
+                                                ),
+                                            );
+                                        }
+                                    };
+                                validate_update_relationship_descriptor(
+                                    action,
+                                    relationship_descriptor,
+                                    original_action,
+                                    original_relationship_descriptor,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::HolonCollectionDescriptor(holon_collection_descriptor) => {
+                            let result = validate_create_holon_collection_descriptor(
+                                EntryCreationAction::Update(action.clone()),
+                                holon_collection_descriptor.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_holon_collection_descriptor: Option<
+                                    HolonCollectionDescriptor,
+                                > = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_holon_collection_descriptor =
+                                    match original_holon_collection_descriptor {
+                                        Some(holon_collection_descriptor) => {
+                                            holon_collection_descriptor
+                                        }
+                                        None => {
+                                            return Ok(
+                                                ValidateCallbackResult::Invalid(
+                                                    "The updated entry type must be the same as the original entry type"
+                                                        .to_string(),
+                                                ),
+                                            );
+                                        }
+                                    };
+                                validate_update_holon_collection_descriptor(
+                                    action,
+                                    holon_collection_descriptor,
+                                    original_action,
+                                    original_holon_collection_descriptor,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::TypeNameClaim(type_name_claim) => {
+                            let original_type_name_claim: Option<TypeNameClaim> = original_record
+                                .entry()
+                                .to_app_option()
+                                .map_err(|e| wasm_error!(e))?;
+                            let original_type_name_claim = match original_type_name_claim {
+                                Some(type_name_claim) => type_name_claim,
+                                None => {
+                                    return Ok(
+                                        ValidateCallbackResult::Invalid(
+                                            "The updated entry type must be the same as the original entry type"
+                                                .to_string(),
+                                        ),
+                                    );
+                                }
+                            };
+                            validate_update_type_name_claim(
+                                action,
+                                type_name_claim,
+                                original_action,
+                                original_type_name_claim,
+                            )
+                        }
                     }
                 }
                 OpRecord::DeleteEntry { original_action_hash, action, .. } => {
@@ -422,6 +936,32 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_value_descriptor,
                             )
                         }
+                        EntryTypes::Holon(original_holon) => {
+                            validate_delete_holon(action, original_action, original_holon)
+                        }
+                        EntryTypes::RelationshipDescriptor(original_relationship_descriptor) => {
+                            validate_delete_relationship_descriptor(
+                                action,
+                                original_action,
+                                original_relationship_descriptor,
+                            )
+                        }
+                        EntryTypes::HolonCollectionDescriptor(
+                            original_holon_collection_descriptor,
+                        ) => {
+                            validate_delete_holon_collection_descriptor(
+                                action,
+                                original_action,
+                                original_holon_collection_descriptor,
+                            )
+                        }
+                        EntryTypes::TypeNameClaim(original_type_name_claim) => {
+                            validate_delete_type_name_claim(
+                                action,
+                                original_action,
+                                original_type_name_claim,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreateLink {
@@ -464,6 +1004,78 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 tag,
                             )
                         }
+                        LinkTypes::TypeNameClaims => {
+                            validate_create_link_type_name_claims(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::HolonsOfType => {
+                            validate_create_link_holons_of_type(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::RelationshipDescriptorUpdates => {
+                            validate_create_link_relationship_descriptor_updates(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AllRelationshipTypes => {
+                            validate_create_link_all_relationship_types(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::HolonCollectionDescriptorUpdates => {
+                            validate_create_link_holon_collection_descriptor_updates(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AllHolonCollectionTypes => {
+                            validate_create_link_all_holon_collection_types(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::UsedBy => {
+                            validate_create_link_used_by(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::DescriptorsOfBaseType => {
+                            validate_create_link_descriptors_of_base_type(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::DescriptorsByToken => {
+                            validate_create_link_descriptors_by_token(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::DeleteLink { original_action_hash, base_address, action } => {
@@ -525,6 +1137,87 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 create_link.tag,
                             )
                         }
+                        LinkTypes::TypeNameClaims => {
+                            validate_delete_link_type_name_claims(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::HolonsOfType => {
+                            validate_delete_link_holons_of_type(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::RelationshipDescriptorUpdates => {
+                            validate_delete_link_relationship_descriptor_updates(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AllRelationshipTypes => {
+                            validate_delete_link_all_relationship_types(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::HolonCollectionDescriptorUpdates => {
+                            validate_delete_link_holon_collection_descriptor_updates(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AllHolonCollectionTypes => {
+                            validate_delete_link_all_holon_collection_types(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::UsedBy => {
+                            validate_delete_link_used_by(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::DescriptorsOfBaseType => {
+                            validate_delete_link_descriptors_of_base_type(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::DescriptorsByToken => {
+                            validate_delete_link_descriptors_by_token(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
@@ -0,0 +1,46 @@
+use hdi::prelude::*;
+
+use crate::revision_validators::validate_revision_ancestry;
+use crate::source_chain_validators::chain_contains;
+use crate::{entry_types_from_record, EntryTypes};
+
+/// Links that index an entry are deleted as part of deleting that entry, which need not be done
+/// by the agent who created the link. Such a DeleteLink is therefore justified by the Delete that
+/// precedes it on its author's source chain, rather than by who authored the link.
+///
+/// Returns true if some action committed before `action` by the same agent satisfies
+/// `is_justification`.
+pub fn is_preceded_by(
+    action: &DeleteLink,
+    is_justification: impl FnMut(&SignedActionHashed) -> ExternResult<bool>,
+) -> ExternResult<bool> {
+    chain_contains(&action.author, &action.prev_action, is_justification)
+}
+
+/// Returns true if the author of `action` deleted `deleted` before deleting the link
+pub fn is_preceded_by_delete_of(action: &DeleteLink, deleted: &ActionHash) -> ExternResult<bool> {
+    is_preceded_by(action, |earlier| {
+        Ok(matches!(earlier.action(), Action::Delete(delete) if &delete.deletes_address == deleted))
+    })
+}
+
+/// Returns true if the author of `action` revised the entry created at `original_hash` before
+/// deleting the link, by an Update whose entry satisfies `is_justification` (e.g. one that no
+/// longer makes the reference the link records)
+pub fn is_preceded_by_revision_of(
+    action: &DeleteLink,
+    original_hash: &ActionHash,
+    mut is_justification: impl FnMut(EntryTypes) -> bool,
+) -> ExternResult<bool> {
+    is_preceded_by(action, |earlier| {
+        if !matches!(earlier.action(), Action::Update(_)) {
+            return Ok(false);
+        }
+        let record = must_get_valid_record(earlier.as_hash().clone())?;
+        let ancestry = validate_revision_ancestry(original_hash, &record)?;
+        if !matches!(ancestry, ValidateCallbackResult::Valid) {
+            return Ok(false);
+        }
+        Ok(entry_types_from_record(&record)?.map_or(false, &mut is_justification))
+    })
+}
@@ -0,0 +1,99 @@
+use hdi::hash_path::path::{Component, Path};
+use hdi::prelude::*;
+use shared_types_descriptor::type_header::TypeHeader;
+
+use crate::link_deletion_validators::is_preceded_by_delete_of;
+
+pub const ALL_HOLON_TYPES_PATH: &str = "all_holon_types";
+pub const ALL_VALUE_DESCRIPTORS_PATH: &str = "all_value_descriptors";
+pub const ALL_RELATIONSHIP_TYPES_PATH: &str = "all_relationship_types";
+pub const ALL_HOLON_COLLECTION_TYPES_PATH: &str = "all_holon_collection_types";
+// the shard of type_names that do not start with an ASCII letter or digit
+const OTHER_SHARD: &str = "_";
+
+/// HolonDescriptors and ValueDescriptors are listed by AllHolonTypes and AllValueDescriptors
+/// links, tagged with the type_name of the descriptor and targeting its original ActionHash.
+/// Rather than hanging every descriptor of a kind off a single path, each listing is split into
+/// shards by the first character of the type_name (folded to lowercase), plus one shard for the
+/// type_names that start with anything else. The tag lets the listing be sorted and paged
+/// without fetching the descriptors themselves.
+pub fn listing_shard(type_name: &str) -> String {
+    match type_name.chars().next() {
+        Some(first) if first.is_ascii_alphanumeric() => first.to_ascii_lowercase().to_string(),
+        _ => OTHER_SHARD.to_string(),
+    }
+}
+
+/// Returns every shard of a listing
+pub fn listing_shards() -> Vec<String> {
+    ('0'..='9')
+        .chain('a'..='z')
+        .map(|shard| shard.to_string())
+        .chain(std::iter::once(OTHER_SHARD.to_string()))
+        .collect()
+}
+
+pub fn listing_shard_path(listing: &str, shard: &str) -> Path {
+    Path::from(vec![Component::from(listing), Component::from(shard)])
+}
+
+/// Returns the path of the shard of `listing` that lists `type_name`
+pub fn listing_path(listing: &str, type_name: &str) -> Path {
+    listing_shard_path(listing, &listing_shard(type_name))
+}
+
+/// Checks that a listing link is anchored at the shard for the type_name of the descriptor it
+/// targets, and tagged with that type_name
+pub fn validate_listing_link(
+    link_type_name: &str,
+    listing: &str,
+    base_address: AnyLinkableHash,
+    header: &TypeHeader,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let expected_base = listing_path(listing, &header.type_name).path_entry_hash()?;
+    if base_address != AnyLinkableHash::from(expected_base) {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "{link_type_name} link for {} is not anchored at the shard for that type_name",
+            header.type_name
+        )));
+    }
+    if tag.0 != header.type_name.as_bytes() {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "{link_type_name} link for {} must be tagged with its type_name",
+            header.type_name
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// RelationshipDescriptors and HolonCollectionDescriptors are few enough to be listed from a
+/// single path per kind. Checks that a link of such a listing is anchored at that path.
+pub fn validate_single_path_listing_link(
+    link_type_name: &str,
+    listing: &str,
+    base_address: AnyLinkableHash,
+) -> ExternResult<ValidateCallbackResult> {
+    let expected_base = Path::from(listing).path_entry_hash()?;
+    if base_address != AnyLinkableHash::from(expected_base) {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "{link_type_name} links must be anchored at the {listing} path"
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Any agent may delete a descriptor, so its listing link may be deleted by whoever deleted the
+/// descriptor, once they have done so
+pub fn validate_delete_listing_link(
+    link_type_name: &str,
+    action: &DeleteLink,
+    target_address: AnyLinkableHash,
+) -> ExternResult<ValidateCallbackResult> {
+    if !is_preceded_by_delete_of(action, &ActionHash::from(target_address))? {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "{link_type_name} links can only be deleted after the descriptor they target"
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,102 @@
+use hdi::prelude::*;
+
+use shared_types_descriptor::holon_descriptor::{HolonDescriptor, HolonReference};
+use shared_types_descriptor::validation::{
+    holon_shared_value_references, shared_value_references, validate_embedded_copy,
+    validate_reference_name, SharedValueReference,
+};
+use shared_types_descriptor::value_descriptor::ValueDescriptor;
+
+use crate::{entry_types_from_record, EntryTypes};
+
+/// Verifies that `reference` identifies a valid HolonDescriptor, of the type_name it names if
+/// any. `role` names the referencing field in the messages of Invalid results (e.g. "The
+/// source_type of a RelationshipDescriptor").
+pub fn validate_holon_type_exists(
+    role: &str,
+    reference: &HolonReference,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = match &reference.id {
+        Some(action_hash) => action_hash.clone(),
+        None => {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "{role} must identify a HolonDescriptor by ActionHash"
+            )));
+        }
+    };
+    let record = must_get_valid_record(action_hash)?;
+    let stored = match entry_types_from_record(&record)? {
+        Some(EntryTypes::HolonDescriptor(stored)) => stored,
+        _ => {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "{role} must be a HolonDescriptor"
+            )));
+        }
+    };
+    if let Err(e) = validate_reference_name(role, reference, &stored.header) {
+        return e.into();
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+/// Verifies that every stored ValueDescriptor referenced within `descriptor` exists and has the
+/// type_name its reference names, and that each embedded copy matches the identity of the
+/// descriptor it copies
+pub fn validate_shared_value_references(
+    descriptor: &ValueDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_each_shared_value_reference(shared_value_references(descriptor))
+}
+
+/// Verifies the shared references made by the properties of a HolonDescriptor
+pub fn validate_holon_shared_value_references(
+    descriptor: &HolonDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_each_shared_value_reference(holon_shared_value_references(descriptor))
+}
+
+fn validate_each_shared_value_reference(
+    references: Vec<SharedValueReference>,
+) -> ExternResult<ValidateCallbackResult> {
+    for shared in references {
+        let result = validate_shared_value_reference(&shared)?;
+        if !matches!(result, ValidateCallbackResult::Valid) {
+            return Ok(result);
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+fn validate_shared_value_reference(
+    shared: &SharedValueReference,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = match &shared.reference.id {
+        Some(action_hash) => action_hash.clone(),
+        None => {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "{} must identify a ValueDescriptor by ActionHash",
+                shared.path
+            )));
+        }
+    };
+    let record = must_get_valid_record(action_hash)?;
+    // decoded by entry type, since any other entry would be an Err rather than None
+    let stored = match entry_types_from_record(&record)? {
+        Some(EntryTypes::ValueDescriptor(stored)) => stored,
+        _ => {
+            return Ok(ValidateCallbackResult::Invalid(format!(
+                "{} must be a ValueDescriptor",
+                shared.path
+            )));
+        }
+    };
+    if let Err(e) = validate_reference_name(&shared.path, &shared.reference, &stored.header) {
+        return e.into();
+    }
+    if let Some(embedded) = &shared.embedded {
+        if let Err(e) = validate_embedded_copy(&shared.path, embedded, &stored) {
+            return e.into();
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,156 @@
+use hdi::prelude::*;
+
+use shared_types_descriptor::relationship_descriptor::RelationshipDescriptor;
+use shared_types_descriptor::validation::{
+    validate_header_identity, validate_relationship_descriptor,
+};
+use shared_types_descriptor::versioning::{
+    classify_relationship_descriptor_change, validate_version_bump,
+};
+
+use crate::listing_validators::{
+    validate_delete_listing_link, validate_single_path_listing_link, ALL_RELATIONSHIP_TYPES_PATH,
+};
+use crate::reference_validators::validate_holon_type_exists;
+use crate::revision_validators::validate_revision_ancestry;
+use crate::type_name_validators::validate_type_name_claimed;
+
+fn validate_relationship_endpoints(
+    relationship_descriptor: &RelationshipDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    let result = validate_holon_type_exists(
+        "The source_type of a RelationshipDescriptor",
+        &relationship_descriptor.source_type,
+    )?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    validate_holon_type_exists(
+        "The target_type of a RelationshipDescriptor",
+        &relationship_descriptor.target_type,
+    )
+}
+
+pub fn validate_create_relationship_descriptor(
+    action: EntryCreationAction,
+    relationship_descriptor: RelationshipDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Err(e) = validate_relationship_descriptor(&relationship_descriptor) {
+        return e.into();
+    }
+    let result = validate_type_name_claimed(&action, &relationship_descriptor.header)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    validate_relationship_endpoints(&relationship_descriptor)
+}
+pub fn validate_update_relationship_descriptor(
+    _action: Update,
+    relationship_descriptor: RelationshipDescriptor,
+    _original_action: EntryCreationAction,
+    original_relationship_descriptor: RelationshipDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Err(e) = validate_relationship_descriptor(&relationship_descriptor) {
+        return e.into();
+    }
+    if let Err(e) = validate_header_identity(
+        &original_relationship_descriptor.header,
+        &relationship_descriptor.header,
+    ) {
+        return e.into();
+    }
+    let change = classify_relationship_descriptor_change(
+        &original_relationship_descriptor,
+        &relationship_descriptor,
+    );
+    if let Err(e) = validate_version_bump(
+        &original_relationship_descriptor.header.version,
+        &relationship_descriptor.header.version,
+        change,
+    ) {
+        return e.into();
+    }
+    validate_relationship_endpoints(&relationship_descriptor)
+}
+pub fn validate_delete_relationship_descriptor(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_relationship_descriptor: RelationshipDescriptor,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_relationship_descriptor_updates(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let original_hash = ActionHash::from(base_address);
+    let record = must_get_valid_record(original_hash.clone())?;
+    let _relationship_descriptor: RelationshipDescriptor = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let _relationship_descriptor: RelationshipDescriptor = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    validate_revision_ancestry(&original_hash, &record)
+}
+pub fn validate_delete_link_relationship_descriptor_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("RelationshipDescriptorUpdates links cannot be deleted"),
+        ),
+    )
+}
+pub fn validate_create_link_all_relationship_types(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    let _relationship_descriptor: RelationshipDescriptor = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+            ),
+        )?;
+    validate_single_path_listing_link(
+        "AllRelationshipTypes",
+        ALL_RELATIONSHIP_TYPES_PATH,
+        base_address,
+    )
+}
+pub fn validate_delete_link_all_relationship_types(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_delete_listing_link("AllRelationshipTypes", &action, target)
+}
@@ -0,0 +1,32 @@
+use hdi::prelude::*;
+
+/// Validates that `target_record` is an Update whose chain of original_action_address hops
+/// leads back to `original_hash`, i.e. that it really is a revision of the entry created there.
+/// Used to validate the links that chain every revision to its original.
+pub fn validate_revision_ancestry(
+    original_hash: &ActionHash,
+    target_record: &Record,
+) -> ExternResult<ValidateCallbackResult> {
+    let mut ancestor_hash = match target_record.action() {
+        Action::Update(update) => update.original_action_address.clone(),
+        _ => {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "The target of an updates link must be an Update action",
+            )));
+        }
+    };
+    loop {
+        if &ancestor_hash == original_hash {
+            return Ok(ValidateCallbackResult::Valid);
+        }
+        let ancestor = must_get_action(ancestor_hash)?;
+        ancestor_hash = match ancestor.action() {
+            Action::Update(update) => update.original_action_address.clone(),
+            _ => {
+                return Ok(ValidateCallbackResult::Invalid(String::from(
+                    "The target of an updates link must be a revision of the link base",
+                )));
+            }
+        };
+    }
+}
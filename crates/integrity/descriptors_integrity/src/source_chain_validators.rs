@@ -0,0 +1,37 @@
+use hdi::prelude::*;
+
+// how many actions of a source chain are fetched at a time
+const CHAIN_PAGE_SIZE: u32 = 100;
+
+/// Returns true if some action of `author`'s source chain, from `chain_top` back to its start,
+/// satisfies `is_match`. The chain is walked backwards, a page at a time, visiting the latest
+/// actions first, until one matches or the chain runs out.
+pub fn chain_contains(
+    author: &AgentPubKey,
+    chain_top: &ActionHash,
+    mut is_match: impl FnMut(&SignedActionHashed) -> ExternResult<bool>,
+) -> ExternResult<bool> {
+    let mut chain_top = chain_top.clone();
+    loop {
+        let mut activity = must_get_agent_activity(
+            author.clone(),
+            ChainFilter::new(chain_top).take(CHAIN_PAGE_SIZE),
+        )?;
+        activity.sort_by_key(|item| std::cmp::Reverse(item.action.action().action_seq()));
+        for item in activity.iter() {
+            if is_match(&item.action)? {
+                return Ok(true);
+            }
+        }
+        if activity.len() < CHAIN_PAGE_SIZE as usize {
+            return Ok(false);
+        }
+        let earliest = activity.last().map(|item| item.action.action());
+        chain_top = match earliest.and_then(|earliest| earliest.prev_action()) {
+            Some(prev_action) => prev_action.clone(),
+            None => {
+                return Ok(false);
+            }
+        };
+    }
+}
@@ -0,0 +1,164 @@
+use hdi::hash_path::path::{Component, Path};
+use hdi::prelude::*;
+use shared_types_descriptor::type_header::TypeHeader;
+use shared_types_descriptor::type_name_claim::TypeNameClaim;
+use std::collections::BTreeSet;
+
+use crate::entry_types_from_record;
+use crate::source_chain_validators::chain_contains;
+
+pub const TYPE_NAMES_PATH: &str = "type_names";
+
+/// Every stored descriptor claims its type_name twice: by the TypeNameClaim entry committed
+/// immediately before its original Create, and by a TypeNameClaims link from the path returned
+/// by `type_name_path` to that original ActionHash. The path is derived here so that the
+/// coordinator and the validation rules always agree on it.
+///
+/// NOTE: validation can only rule out an agent claiming a type_name twice (see
+/// `validate_create_type_name_claim`). It cannot prove the *absence* of another agent's claim on
+/// the DHT, so two agents on a partitioned network may both create a descriptor with the same
+/// type_name. Both creates are valid, but only the one that follows the earliest TypeNameClaim
+/// action owns the name; the coordinator resolves names accordingly and refuses to claim a name
+/// it already sees claimed. Claims, and the links to their descriptors, are permanent.
+pub fn type_name_path(type_name: &str) -> Path {
+    Path::from(vec![
+        Component::from(TYPE_NAMES_PATH),
+        Component::from(type_name),
+    ])
+}
+
+pub fn validate_create_link_type_name_claims(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::from(target_address);
+    let record = must_get_valid_record(action_hash)?;
+    if record.action().action_type() != ActionType::Create {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "TypeNameClaims links must target the original Create of a descriptor",
+        )));
+    }
+    let header = match entry_types_from_record(&record)?.and_then(|e| e.type_header().cloned()) {
+        Some(header) => header,
+        None => {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "TypeNameClaims links must target a descriptor",
+            )));
+        }
+    };
+    let expected_base = type_name_path(&header.type_name).path_entry_hash()?;
+    if base_address != AnyLinkableHash::from(expected_base) {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "TypeNameClaims link for {} is not anchored at the path for that type_name",
+            header.type_name
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_type_name_claims(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            String::from("TypeNameClaims links cannot be deleted"),
+        ),
+    )
+}
+
+/// The original Create of a descriptor is only valid if it directly follows the Create of the
+/// TypeNameClaim for its type_name. Updates keep the type_name of their original, so they need
+/// no claim of their own.
+pub fn validate_type_name_claimed(
+    action: &EntryCreationAction,
+    header: &TypeHeader,
+) -> ExternResult<ValidateCallbackResult> {
+    let create = match action {
+        EntryCreationAction::Create(create) => create,
+        EntryCreationAction::Update(_) => {
+            return Ok(ValidateCallbackResult::Valid);
+        }
+    };
+    let claim_hash = hash_entry(&TypeNameClaim::new(header.type_name.clone()))?;
+    let previous_action = must_get_action(create.prev_action.clone())?;
+    match previous_action.action() {
+        Action::Create(previous) if previous.entry_hash == claim_hash => {
+            Ok(ValidateCallbackResult::Valid)
+        }
+        _ => Ok(ValidateCallbackResult::Invalid(format!(
+            "The Create of descriptor {} must directly follow the TypeNameClaim for it",
+            header.type_name
+        ))),
+    }
+}
+
+/// An agent may not claim a type_name it has already claimed, unless it has since deleted the
+/// descriptor created right after its earlier claim (which releases the name). A descriptor
+/// deleted by some other agent does not release the name for its creator.
+pub fn validate_create_type_name_claim(
+    action: EntryCreationAction,
+    type_name_claim: TypeNameClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    let create = match action {
+        EntryCreationAction::Create(create) => create,
+        EntryCreationAction::Update(_) => {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "TypeNameClaims cannot be updated",
+            )));
+        }
+    };
+    if type_name_claim.type_name.is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "A TypeNameClaim must name a type",
+        )));
+    }
+    let claim_hash = hash_entry(&type_name_claim)?;
+    // the chain is walked from the latest action back, so by the time an earlier claim is
+    // reached, the action that followed it and every later Delete have been seen
+    let mut deleted: BTreeSet<ActionHash> = BTreeSet::new();
+    let mut following: Option<ActionHash> = None;
+    let claimed_before = chain_contains(&create.author, &create.prev_action, |earlier| {
+        let is_live_claim = match earlier.action() {
+            Action::Create(claim) if claim.entry_hash == claim_hash => following
+                .as_ref()
+                .map_or(true, |descriptor| !deleted.contains(descriptor)),
+            Action::Delete(delete) => {
+                deleted.insert(delete.deletes_address.clone());
+                false
+            }
+            _ => false,
+        };
+        following = Some(earlier.as_hash().clone());
+        Ok(is_live_claim)
+    })?;
+    if claimed_before {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "{} has already claimed the type_name {}",
+            create.author, type_name_claim.type_name
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_type_name_claim(
+    _action: Update,
+    _type_name_claim: TypeNameClaim,
+    _original_action: EntryCreationAction,
+    _original_type_name_claim: TypeNameClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("TypeNameClaims cannot be updated")))
+}
+
+pub fn validate_delete_type_name_claim(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_type_name_claim: TypeNameClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(String::from("TypeNameClaims cannot be deleted")))
+}
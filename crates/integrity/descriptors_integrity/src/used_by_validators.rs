@@ -0,0 +1,79 @@
+use hdi::prelude::*;
+
+use shared_types_descriptor::validation::{
+    holon_shared_value_references, shared_value_references, SharedValueReference,
+};
+
+use crate::link_deletion_validators::{is_preceded_by_delete_of, is_preceded_by_revision_of};
+use crate::{entry_types_from_record, EntryTypes};
+
+/// A UsedBy link points from the original of a shared ValueDescriptor to the original of a
+/// ValueDescriptor or HolonDescriptor that references it. Its tag holds the property path
+/// through which the dependent uses the shared descriptor (e.g. `Person.address`).
+pub fn validate_create_link_used_by(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(ActionHash::from(base_address))?;
+    if !matches!(entry_types_from_record(&record)?, Some(EntryTypes::ValueDescriptor(_))) {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "UsedBy links must be based at a ValueDescriptor",
+        )));
+    }
+    let record = must_get_valid_record(ActionHash::from(target_address))?;
+    match entry_types_from_record(&record)? {
+        Some(EntryTypes::ValueDescriptor(_)) | Some(EntryTypes::HolonDescriptor(_)) => {}
+        _ => {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+                "UsedBy links must target a ValueDescriptor or HolonDescriptor",
+            )));
+        }
+    }
+    match String::from_utf8(tag.0) {
+        Ok(path) if !path.is_empty() => Ok(ValidateCallbackResult::Valid),
+        _ => Ok(ValidateCallbackResult::Invalid(String::from(
+            "UsedBy links must be tagged with the path of the referencing property",
+        ))),
+    }
+}
+
+/// Any agent may revise or delete a descriptor, so a UsedBy link may be deleted by whoever
+/// deleted its dependent, or revised the dependent so that it no longer makes the reference.
+pub fn validate_delete_link_used_by(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let shared_hash = ActionHash::from(base);
+    let dependent_hash = ActionHash::from(target);
+    if is_preceded_by_delete_of(&action, &dependent_hash)? {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let makes_reference = |references: Vec<SharedValueReference>| {
+        references.iter().any(|shared| {
+            shared.reference.id.as_ref() == Some(&shared_hash) && shared.path.as_bytes() == tag.0
+        })
+    };
+    let dropped = is_preceded_by_revision_of(&action, &dependent_hash, |revision| {
+        match revision {
+            EntryTypes::ValueDescriptor(descriptor) => {
+                !makes_reference(shared_value_references(&descriptor))
+            }
+            EntryTypes::HolonDescriptor(descriptor) => {
+                !makes_reference(holon_shared_value_references(&descriptor))
+            }
+            _ => false,
+        }
+    })?;
+    if !dropped {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+            "UsedBy links can only be deleted after their dependent is deleted or stops using the \
+             shared descriptor",
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -1,23 +1,68 @@
 use hdi::prelude::*;
+use shared_types_descriptor::validation::{validate_header_identity, validate_value_descriptor};
+use shared_types_descriptor::versioning::{classify_value_descriptor_change, validate_version_bump};
 use shared_types_descriptor::value_descriptor::{ValueDescriptor};
 
+use crate::cycle_validators::validate_acyclic_value_type;
+use crate::listing_validators::{
+    validate_delete_listing_link, validate_listing_link, ALL_VALUE_DESCRIPTORS_PATH,
+};
+use crate::reference_validators::validate_shared_value_references;
+use crate::revision_validators::validate_revision_ancestry;
+use crate::type_name_validators::validate_type_name_claimed;
+
 
 pub fn validate_create_value_descriptor(
-    _action: EntryCreationAction,
-    _value_descriptor: ValueDescriptor,
+    action: EntryCreationAction,
+    value_descriptor: ValueDescriptor,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    if let Err(e) = validate_value_descriptor(&value_descriptor) {
+        return e.into();
+    }
+    let result = validate_type_name_claimed(&action, &value_descriptor.header)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    let result = validate_shared_value_references(&value_descriptor)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    validate_acyclic_value_type(&value_descriptor)
 }
 
 pub fn validate_update_value_descriptor(
     _action: Update,
-    _value_descriptor: ValueDescriptor,
+    value_descriptor: ValueDescriptor,
     _original_action: EntryCreationAction,
-    _original_value_descriptor: ValueDescriptor,
+    original_value_descriptor: ValueDescriptor,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    if let Err(e) = validate_value_descriptor(&value_descriptor) {
+        return e.into();
+    }
+    if let Err(e) =
+        validate_header_identity(&original_value_descriptor.header, &value_descriptor.header)
+    {
+        return e.into();
+    }
+    let change = classify_value_descriptor_change(&original_value_descriptor, &value_descriptor);
+    if let Err(e) = validate_version_bump(
+        &original_value_descriptor.header.version,
+        &value_descriptor.header.version,
+        change,
+    ) {
+        return e.into();
+    }
+    let result = validate_shared_value_references(&value_descriptor)?;
+    if !matches!(result, ValidateCallbackResult::Valid) {
+        return Ok(result);
+    }
+    validate_acyclic_value_type(&value_descriptor)
 }
 
+/// Deleting a shared ValueDescriptor is not refused here even if other descriptors still use
+/// it: validation cannot enumerate its UsedBy links, and the Force DeletionMode deliberately
+/// leaves dependents dangling. The dependents guard is applied by the coordinator, and is
+/// advisory only.
 pub fn validate_delete_value_descriptor(
     _action: Delete,
     _original_action: EntryCreationAction,
@@ -32,8 +77,8 @@ pub fn validate_create_link_value_descriptor_updates(
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    let action_hash = ActionHash::from(base_address);
-    let record = must_get_valid_record(action_hash)?;
+    let original_hash = ActionHash::from(base_address);
+    let record = must_get_valid_record(original_hash.clone())?;
     let _value_descriptor: ValueDescriptor = record
         .entry()
         .to_app_option()
@@ -54,7 +99,7 @@ pub fn validate_create_link_value_descriptor_updates(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
-    Ok(ValidateCallbackResult::Valid)
+    validate_revision_ancestry(&original_hash, &record)
 }
 
 pub fn validate_delete_link_value_descriptor_updates(
@@ -73,13 +118,13 @@ pub fn validate_delete_link_value_descriptor_updates(
 
 pub fn validate_create_link_all_value_types(
     _action: CreateLink,
-    _base_address: AnyLinkableHash,
+    base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
-    _tag: LinkTag,
+    tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
     let action_hash = ActionHash::from(target_address);
     let record = must_get_valid_record(action_hash)?;
-    let _value_descriptor: ValueDescriptor = record
+    let value_descriptor: ValueDescriptor = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -88,19 +133,21 @@ pub fn validate_create_link_all_value_types(
                 WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
             ),
         )?;
-    Ok(ValidateCallbackResult::Valid)
+    validate_listing_link(
+        "AllValueDescriptors",
+        ALL_VALUE_DESCRIPTORS_PATH,
+        base_address,
+        &value_descriptor.header,
+        tag,
+    )
 }
 
 pub fn validate_delete_link_all_value_types(
-    _action: DeleteLink,
+    action: DeleteLink,
     _original_action: CreateLink,
     _base: AnyLinkableHash,
-    _target: AnyLinkableHash,
+    target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(
-        ValidateCallbackResult::Invalid(
-            String::from("AllValueTypes links cannot be deleted"),
-        ),
-    )
-}
\ No newline at end of file
+    validate_delete_listing_link("AllValueDescriptors", &action, target)
+}
@@ -0,0 +1,35 @@
+use hdi::prelude::*;
+use std::collections::BTreeMap;
+
+/// BaseValue is the in-memory (and serialized) form of a value described by a ValueDescriptor.
+/// Each variant corresponds to one of the ValueDescriptorDetails kinds, except that Boolean
+/// descriptors with `is_fuzzy` set also accept FuzzyBoolean values.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub enum BaseValue {
+    Boolean(bool),
+    FuzzyBoolean(u8), // degree of truth, from 0 (false) to FUZZY_TRUE (true)
+    Integer(i64),
+    String(String),
+    Enum(String), // the name of one of the EnumDescriptor's variants
+    Collection(Vec<BaseValue>),
+    Composite(BTreeMap<String, BaseValue>),
+}
+
+pub const FUZZY_TRUE: u8 = 100;
+
+impl BaseValue {
+    /// A short name for the kind of value, used when reporting violations
+    pub fn kind(&self) -> &'static str {
+        match self {
+            BaseValue::Boolean(_) => "Boolean",
+            BaseValue::FuzzyBoolean(_) => "FuzzyBoolean",
+            BaseValue::Integer(_) => "Integer",
+            BaseValue::String(_) => "String",
+            BaseValue::Enum(_) => "Enum",
+            BaseValue::Collection(_) => "Collection",
+            BaseValue::Composite(_) => "Composite",
+        }
+    }
+}
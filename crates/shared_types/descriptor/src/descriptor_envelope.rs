@@ -0,0 +1,21 @@
+use crate::type_header::SemanticVersion;
+use hdi::prelude::*;
+
+/// DescriptorEnvelope carries the latest revision of a stored descriptor, already decoded,
+/// together with the hashes and provenance that callers would otherwise have to dig out of its
+/// Records.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DescriptorEnvelope<T> {
+    pub descriptor: T,
+    // the ActionHash of the Create that stored the first revision
+    pub original_hash: ActionHash,
+    // the ActionHash of the latest revision (the original_hash if it was never updated)
+    pub revision_hash: ActionHash,
+    // who created the descriptor and when
+    pub author: AgentPubKey,
+    pub created_at: Timestamp,
+    // who committed the latest revision and when
+    pub revision_author: AgentPubKey,
+    pub updated_at: Timestamp,
+    pub version: SemanticVersion,
+}
@@ -1,10 +1,64 @@
 use hdk::prelude::*;
 use thiserror::Error;
 
+use crate::type_header::{BaseType, SemanticVersion};
+use crate::value_validation::ValueViolation;
+use crate::versioning::ChangeKind;
+
 #[derive(Error, Debug, Eq, PartialEq)]
 pub enum DescriptorsError {
     #[error("{0} field is missing")]
     EmptyField(String),
+    #[error("{0}: header base_type {1} does not match {2} details")]
+    BaseTypeMismatch(String, BaseType, BaseType),
+    #[error("{0}: min_length ({1}) is greater than max_length ({2})")]
+    InvalidLengthRange(String, u32, u32),
+    #[error("{0}: min_value ({1}) is greater than max_value ({2})")]
+    InvalidValueRange(String, i64, i64),
+    #[error("{0}: min_items ({1}) is greater than max_items ({2})")]
+    InvalidItemCountRange(String, u32, u32),
+    #[error("{0}: pattern {1} is not a valid regular expression: {2}")]
+    InvalidPattern(String, String, String),
+    #[error("{0}: min_target_cardinality ({1}) is greater than max_target_cardinality ({2})")]
+    InvalidCardinalityRange(String, u32, u32),
+    #[error("{0}: reference does not identify a descriptor by ActionHash")]
+    UnresolvedReference(String),
+    #[error("{0}: an enum must have at least one variant")]
+    EmptyEnum(String),
+    #[error("{0}: enum variant {1} is declared more than once")]
+    DuplicateEnumVariant(String, String),
+    #[error("{0}: enum code {1} is used by more than one variant")]
+    DuplicateEnumCode(String, i64),
+    #[error("type_name {0} is already claimed by another descriptor")]
+    DuplicateTypeName(String),
+    #[error("{0} cannot be changed by an update (from {1} to {2})")]
+    ImmutableFieldChanged(String, String, String),
+    #[error("version {1} is not a valid {2} revision of version {0}")]
+    InsufficientVersionBump(SemanticVersion, SemanticVersion, ChangeKind),
+    #[error("version {0} cannot be bumped for a {1} change")]
+    VersionOverflow(SemanticVersion, ChangeKind),
+    #[error("revision {0} is stale, the latest revision is {1}")]
+    StaleRevision(ActionHash, ActionHash),
+    #[error("descriptor revision {0} has version {1}, not {2}")]
+    DescriptorVersionMismatch(ActionHash, SemanticVersion, SemanticVersion),
+    #[error("{0}: embedded {1} {2} does not match the shared descriptor's {1} {3}")]
+    SharedDescriptorMismatch(String, String, String, String),
+    #[error("descriptor {0} is still used by {}", .1.join(", "))]
+    DescriptorInUse(ActionHash, Vec<String>),
+    #[error("type graph contains a cycle: {}", .0.join(" -> "))]
+    CyclicTypeGraph(Vec<String>),
+    #[error("{0}: shared reference to {1} forms a cycle")]
+    CyclicSharedReference(String, ActionHash),
+    #[error("{0}: no revision of descriptor {1} has version {2}")]
+    MissingDescriptorVersion(String, ActionHash, SemanticVersion),
+    #[error("{}", .0.iter().map(|v| v.to_string()).collect::<Vec<String>>().join("; "))]
+    NonConformingValue(Vec<ValueViolation>),
+    #[error("no descriptor claims type_name {0}")]
+    UnknownTypeName(String),
+    #[error("{0} is not a holon or value descriptor, so it cannot be bundled")]
+    UnbundleableDescriptor(String),
+    #[error("{0}: reference names type_name {1}, but identifies {2}")]
+    ReferenceNameMismatch(String, String, String),
     // #[error("Element missing its Entry")]
     // ValidationError,
 
@@ -15,14 +69,14 @@ pub enum DescriptorsError {
     // Wasm(WasmError),
 }
 
-// impl From<DescriptorsError> for ValidateCallbackResult {
-//     fn from(e: DescriptorsError) -> Self {
-//         ValidateCallbackResult::Invalid(e.to_string())
-//     }
-// }
+impl From<DescriptorsError> for ValidateCallbackResult {
+    fn from(e: DescriptorsError) -> Self {
+        ValidateCallbackResult::Invalid(e.to_string())
+    }
+}
 
-// impl From<DescriptorsError> for ExternResult<ValidateCallbackResult> {
-//     fn from(e: DescriptorsError) -> Self {
-//         Ok(e.into())
-//     }
-// }
+impl From<DescriptorsError> for ExternResult<ValidateCallbackResult> {
+    fn from(e: DescriptorsError) -> Self {
+        Ok(e.into())
+    }
+}
@@ -0,0 +1,21 @@
+use crate::base_value::BaseValue;
+use crate::type_header::SemanticVersion;
+use derive_new::new;
+use hdi::prelude::*;
+use std::collections::BTreeMap;
+
+/// A Holon is an instance of the type described by a HolonDescriptor.
+///
+/// The descriptor is identified by its original ActionHash (which is stable across revisions)
+/// and the version the holon conforms to. Because versions alone cannot be resolved
+/// deterministically during validation, the holon also names the specific descriptor revision
+/// carrying that version.
+#[hdk_entry_helper]
+#[derive(new, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Holon {
+    pub descriptor: ActionHash,          // original ActionHash of the HolonDescriptor
+    pub descriptor_revision: ActionHash, // the revision of the HolonDescriptor with this version
+    pub descriptor_version: SemanticVersion,
+    pub property_map: BTreeMap<String, BaseValue>,
+}
@@ -23,7 +23,7 @@ pub struct HolonReference {
 #[serde(rename_all = "camelCase")]
 pub struct HolonCollectionDescriptor {
     pub header: TypeHeader,
-    pub contains_items_of_type: HolonDescriptor,
+    pub contains_items_of_type: HolonReference, // must identify a stored HolonDescriptor
     pub min_items: u32,
     pub max_items: u32,
     pub unique_items: bool,
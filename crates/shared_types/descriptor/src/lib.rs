@@ -1,8 +1,18 @@
 // This crate contains definitions for all of the shared descriptor types.
+pub mod base_value;
+pub mod descriptor_envelope;
 pub mod error;
+pub mod holon;
 pub mod holon_descriptor;
+pub mod relationship_descriptor;
+pub mod schema_bundle;
 pub mod value_descriptor;
+pub mod type_descriptor;
 pub mod type_header;
+pub mod type_name_claim;
+pub mod validation;
+pub mod value_validation;
+pub mod versioning;
 
 // #[cfg(test)]
 // mod tests {
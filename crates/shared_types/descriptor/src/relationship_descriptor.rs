@@ -0,0 +1,31 @@
+use crate::holon_descriptor::HolonReference;
+use crate::type_header::TypeHeader;
+use derive_new::new;
+use hdi::prelude::*;
+
+/// RelationshipDescriptor describes a directed relationship from holons of a source type to
+/// holons of a target type. The source and target types must be HolonDescriptors, referenced by
+/// the ActionHash of their original create.
+#[hdk_entry_helper]
+#[derive(new, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationshipDescriptor {
+    pub header: TypeHeader,
+    pub source_type: HolonReference,
+    pub target_type: HolonReference,
+    pub min_target_cardinality: u32, // number of targets each source holon must relate to
+    pub max_target_cardinality: u32,
+    pub inverse_name: Option<String>, // used to traverse the relationship from target to source
+    pub is_ordered: bool, // if the targets of each source holon have an intrinsic order
+    pub deletion_semantic: DeletionSemantic,
+}
+
+/// DeletionSemantic determines what happens when a source holon of the relationship is deleted
+#[hdk_entry_helper]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum DeletionSemantic {
+    #[default]
+    Allow,   // the relationship is simply removed along with the source holon
+    Block,   // the source holon cannot be deleted while it relates to any targets
+    Cascade, // the targets are deleted along with the source holon
+}
@@ -0,0 +1,57 @@
+use crate::holon_descriptor::HolonDescriptor;
+use crate::type_descriptor::TypeDescriptor;
+use crate::type_header::SemanticVersion;
+use crate::value_descriptor::ValueDescriptor;
+use hdi::prelude::*;
+
+/// SchemaBundle is a set of HolonDescriptors and ValueDescriptors serialized as one document,
+/// e.g. to keep a schema in version control or to carry it between DNAs. Within a bundle,
+/// descriptors refer to each other by type_name only; the ActionHashes of those references are
+/// cleared on export and resolved again on import.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SchemaBundle {
+    pub name: String,
+    pub version: SemanticVersion,
+    // both lists are sorted by type_name
+    pub holon_descriptors: Vec<HolonDescriptor>,
+    pub value_descriptors: Vec<ValueDescriptor>,
+}
+
+impl SchemaBundle {
+    /// Bundles `descriptors`, clearing the ActionHashes of their references that name their
+    /// type (so references are expected to be named first). Descriptors of other kinds are left
+    /// out.
+    pub fn new(name: String, version: SemanticVersion, descriptors: Vec<TypeDescriptor>) -> Self {
+        let mut bundle = SchemaBundle {
+            name,
+            version,
+            holon_descriptors: Vec::new(),
+            value_descriptors: Vec::new(),
+        };
+        for mut descriptor in descriptors {
+            descriptor.clear_reference_ids();
+            match descriptor {
+                TypeDescriptor::Holon(descriptor) => bundle.holon_descriptors.push(descriptor),
+                TypeDescriptor::Value(descriptor) => bundle.value_descriptors.push(descriptor),
+                TypeDescriptor::Relationship(_) | TypeDescriptor::HolonCollection(_) => {}
+            }
+        }
+        bundle
+            .holon_descriptors
+            .sort_by(|a, b| a.header.type_name.cmp(&b.header.type_name));
+        bundle
+            .value_descriptors
+            .sort_by(|a, b| a.header.type_name.cmp(&b.header.type_name));
+        bundle
+    }
+
+    /// Returns the descriptors of the bundle, value descriptors first
+    pub fn descriptors(&self) -> Vec<TypeDescriptor> {
+        self.value_descriptors
+            .iter()
+            .cloned()
+            .map(TypeDescriptor::Value)
+            .chain(self.holon_descriptors.iter().cloned().map(TypeDescriptor::Holon))
+            .collect()
+    }
+}
@@ -0,0 +1,148 @@
+use crate::holon_descriptor::{HolonCollectionDescriptor, HolonDescriptor, HolonReference};
+use crate::relationship_descriptor::RelationshipDescriptor;
+use crate::type_header::TypeHeader;
+use crate::value_descriptor::{
+    DescriptorSharing, PropertyDescriptorMap, ValueDescriptor, ValueDescriptorDetails,
+    ValueItemType,
+};
+use hdi::prelude::*;
+use std::collections::BTreeSet;
+
+/// TypeDescriptor holds a descriptor of any kind, for queries that span the kinds of descriptor
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, Eq)]
+pub enum TypeDescriptor {
+    Holon(HolonDescriptor),
+    Value(ValueDescriptor),
+    Relationship(RelationshipDescriptor),
+    HolonCollection(HolonCollectionDescriptor),
+}
+
+impl TypeDescriptor {
+    pub fn header(&self) -> &TypeHeader {
+        match self {
+            TypeDescriptor::Holon(descriptor) => &descriptor.header,
+            TypeDescriptor::Value(descriptor) => &descriptor.header,
+            TypeDescriptor::Relationship(descriptor) => &descriptor.header,
+            TypeDescriptor::HolonCollection(descriptor) => &descriptor.header,
+        }
+    }
+
+    /// Resolves the references made by this descriptor that name a type but carry no
+    /// ActionHash yet, setting the ActionHash that `resolve` returns for the type_name (if any).
+    /// This covers shared properties and item types, however deeply they are nested in dedicated
+    /// descriptors, plus the endpoints of relationships and the item types of holon collections.
+    pub fn resolve_references(&mut self, resolve: &mut dyn FnMut(&str) -> Option<ActionHash>) {
+        self.visit_references(&mut |reference| {
+            if reference.id.is_none() {
+                if let Some(type_name) = &reference.name {
+                    reference.id = resolve(type_name);
+                }
+            }
+        });
+    }
+
+    /// Returns the type_names of the references that `resolve_references` would resolve
+    pub fn unresolved_reference_names(&self) -> BTreeSet<String> {
+        let mut names = BTreeSet::new();
+        self.clone().resolve_references(&mut |type_name| {
+            names.insert(type_name.to_string());
+            None
+        });
+        names
+    }
+
+    /// Returns the type_names of all the references made by this descriptor, resolved or not
+    pub fn reference_names(&self) -> BTreeSet<String> {
+        let mut names = BTreeSet::new();
+        self.clone().visit_references(&mut |reference| {
+            if let Some(type_name) = &reference.name {
+                names.insert(type_name.clone());
+            }
+        });
+        names
+    }
+
+    /// Returns the ActionHashes of the references that identify their type by ActionHash alone
+    pub fn unnamed_reference_ids(&self) -> BTreeSet<ActionHash> {
+        let mut ids = BTreeSet::new();
+        self.clone().visit_references(&mut |reference| {
+            if let (Some(id), None) = (&reference.id, &reference.name) {
+                ids.insert(id.clone());
+            }
+        });
+        ids
+    }
+
+    /// Names the references that identify their type by ActionHash alone, setting the type_name
+    /// that `name` returns for the ActionHash (if any)
+    pub fn name_references(&mut self, name: &mut dyn FnMut(&ActionHash) -> Option<String>) {
+        self.visit_references(&mut |reference| {
+            if reference.name.is_none() {
+                if let Some(id) = &reference.id {
+                    reference.name = name(id);
+                }
+            }
+        });
+    }
+
+    /// Clears the ActionHash of every reference that names its type, so that the descriptor
+    /// refers to other types by type_name alone and can be resolved again with
+    /// `resolve_references`
+    pub fn clear_reference_ids(&mut self) {
+        self.visit_references(&mut |reference| {
+            if reference.name.is_some() {
+                reference.id = None;
+            }
+        });
+    }
+
+    fn visit_references(&mut self, visit: &mut dyn FnMut(&mut HolonReference)) {
+        match self {
+            TypeDescriptor::Holon(descriptor) => {
+                visit_property_map_references(&mut descriptor.property_map, visit)
+            }
+            TypeDescriptor::Value(descriptor) => visit_value_references(descriptor, visit),
+            TypeDescriptor::Relationship(descriptor) => {
+                visit(&mut descriptor.source_type);
+                visit(&mut descriptor.target_type);
+            }
+            TypeDescriptor::HolonCollection(descriptor) => {
+                visit(&mut descriptor.contains_items_of_type)
+            }
+        }
+    }
+}
+
+fn visit_value_references(
+    descriptor: &mut ValueDescriptor,
+    visit: &mut dyn FnMut(&mut HolonReference),
+) {
+    match &mut descriptor.details {
+        ValueDescriptorDetails::Composite(composite) => {
+            visit_property_map_references(&mut composite.property_map, visit)
+        }
+        ValueDescriptorDetails::ValueCollection(collection) => {
+            match &mut collection.contains_items_of_type {
+                ValueItemType::Shared(reference) => visit(reference),
+                ValueItemType::Dedicated(item_descriptor) => {
+                    visit_value_references(item_descriptor, visit)
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+// As with validation, the embedded copy of a shared descriptor is left as it is
+fn visit_property_map_references(
+    property_map: &mut PropertyDescriptorMap,
+    visit: &mut dyn FnMut(&mut HolonReference),
+) {
+    for usage in property_map.properties.values_mut() {
+        match &mut usage.sharing {
+            DescriptorSharing::Shared(reference) => visit(reference),
+            DescriptorSharing::Dedicated => visit_value_references(&mut usage.descriptor, visit),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use derive_new::new;
 use hdi::prelude::*;
+use std::collections::BTreeSet;
 use std::fmt;
 
 #[hdk_entry_helper]
@@ -39,6 +40,24 @@ pub struct SemanticVersion {
     patch: u8,
 }
 
+impl SemanticVersion {
+    pub fn major(&self) -> u8 {
+        self.major
+    }
+    pub fn minor(&self) -> u8 {
+        self.minor
+    }
+    pub fn patch(&self) -> u8 {
+        self.patch
+    }
+}
+
+impl fmt::Display for SemanticVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
 impl Default for SemanticVersion {
     fn default() -> Self {
         SemanticVersion {
@@ -60,3 +79,25 @@ pub struct TypeHeader {
     pub version: SemanticVersion,
     pub is_dependent: bool,
 }
+
+/// Search tokens shorter than this are too common to be worth indexing
+pub const MIN_SEARCH_TOKEN_LENGTH: usize = 2;
+
+impl TypeHeader {
+    /// Returns the search tokens of this type's label and description
+    pub fn search_tokens(&self) -> BTreeSet<String> {
+        let mut tokens = tokenize(&self.label);
+        tokens.extend(tokenize(&self.description));
+        tokens
+    }
+}
+
+/// Splits `text` into lowercase alphanumeric words, dropping those shorter than
+/// MIN_SEARCH_TOKEN_LENGTH, so that `Street Address (line 1)` yields `address`, `line` and
+/// `street`
+pub fn tokenize(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= MIN_SEARCH_TOKEN_LENGTH)
+        .map(|word| word.to_lowercase())
+        .collect()
+}
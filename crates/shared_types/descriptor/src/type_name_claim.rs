@@ -0,0 +1,14 @@
+use derive_new::new;
+use hdi::prelude::*;
+
+/// TypeNameClaim is committed immediately before the original Create of every descriptor, which
+/// is only valid if it directly follows the claim of its own type_name. A claim holds nothing but
+/// the type_name, so every claim of a given name is the same entry, and the authorities for that
+/// entry hold every action that ever claimed it. When a name has been claimed more than once, the
+/// earliest claim wins.
+#[hdk_entry_helper]
+#[derive(new, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeNameClaim {
+    pub type_name: String,
+}
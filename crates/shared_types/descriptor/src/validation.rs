@@ -0,0 +1,428 @@
+use crate::error::DescriptorsError;
+use crate::holon_descriptor::{HolonCollectionDescriptor, HolonDescriptor, HolonReference};
+use crate::relationship_descriptor::RelationshipDescriptor;
+use crate::type_header::{BaseType, TypeHeader};
+use crate::value_descriptor::{
+    DescriptorSharing, EnumDescriptor, PropertyDescriptorMap, ValueDescriptor,
+    ValueDescriptorDetails, ValueItemType,
+};
+use regex::Regex;
+use std::collections::BTreeSet;
+
+/// Structural checks shared by the mutators (coordinator) and the validation callbacks (integrity).
+/// They only look at the descriptor itself, never at the DHT, so they are safe to run anywhere.
+///
+/// Errors identify the offending descriptor by a dotted path that starts at the root type_name
+/// and follows property names down through nested composites (e.g. `Person.address.street`).
+/// The item type of a value collection is addressed as `items` (e.g. `Person.nicknames.items`).
+
+pub fn validate_holon_descriptor(descriptor: &HolonDescriptor) -> Result<(), DescriptorsError> {
+    let path = descriptor.header.type_name.clone();
+    if descriptor.header.base_type != BaseType::Holon {
+        return Err(DescriptorsError::BaseTypeMismatch(
+            path,
+            descriptor.header.base_type.clone(),
+            BaseType::Holon,
+        ));
+    }
+    validate_property_map(&path, &descriptor.property_map)
+}
+
+pub fn validate_value_descriptor(descriptor: &ValueDescriptor) -> Result<(), DescriptorsError> {
+    validate_value_descriptor_at(&descriptor.header.type_name, descriptor)
+}
+
+pub fn validate_relationship_descriptor(
+    descriptor: &RelationshipDescriptor,
+) -> Result<(), DescriptorsError> {
+    let path = &descriptor.header.type_name;
+    if descriptor.header.base_type != BaseType::Relationship {
+        return Err(DescriptorsError::BaseTypeMismatch(
+            path.clone(),
+            descriptor.header.base_type.clone(),
+            BaseType::Relationship,
+        ));
+    }
+    validate_holon_reference(&format!("{path}.source_type"), &descriptor.source_type)?;
+    validate_holon_reference(&format!("{path}.target_type"), &descriptor.target_type)?;
+    if descriptor.min_target_cardinality > descriptor.max_target_cardinality {
+        return Err(DescriptorsError::InvalidCardinalityRange(
+            path.clone(),
+            descriptor.min_target_cardinality,
+            descriptor.max_target_cardinality,
+        ));
+    }
+    if let Some(inverse_name) = &descriptor.inverse_name {
+        if inverse_name.is_empty() {
+            return Err(DescriptorsError::EmptyField(format!("{path} inverse_name")));
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_holon_collection_descriptor(
+    descriptor: &HolonCollectionDescriptor,
+) -> Result<(), DescriptorsError> {
+    let path = &descriptor.header.type_name;
+    if descriptor.header.base_type != BaseType::Collection {
+        return Err(DescriptorsError::BaseTypeMismatch(
+            path.clone(),
+            descriptor.header.base_type.clone(),
+            BaseType::Collection,
+        ));
+    }
+    validate_holon_reference(
+        &format!("{path}.contains_items_of_type"),
+        &descriptor.contains_items_of_type,
+    )?;
+    if descriptor.min_items > descriptor.max_items {
+        return Err(DescriptorsError::InvalidItemCountRange(
+            path.clone(),
+            descriptor.min_items,
+            descriptor.max_items,
+        ));
+    }
+    Ok(())
+}
+
+/// The identity of a descriptor (type_name, base_type, is_dependent) is fixed by its original
+/// create. Updates may revise everything else, but must leave these fields unchanged.
+pub fn validate_header_identity(
+    original: &TypeHeader,
+    updated: &TypeHeader,
+) -> Result<(), DescriptorsError> {
+    if original.type_name != updated.type_name {
+        return Err(DescriptorsError::ImmutableFieldChanged(
+            "type_name".to_string(),
+            original.type_name.clone(),
+            updated.type_name.clone(),
+        ));
+    }
+    if original.base_type != updated.base_type {
+        return Err(DescriptorsError::ImmutableFieldChanged(
+            "base_type".to_string(),
+            original.base_type.to_string(),
+            updated.base_type.to_string(),
+        ));
+    }
+    if original.is_dependent != updated.is_dependent {
+        return Err(DescriptorsError::ImmutableFieldChanged(
+            "is_dependent".to_string(),
+            original.is_dependent.to_string(),
+            updated.is_dependent.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_value_descriptor_at(
+    path: &str,
+    descriptor: &ValueDescriptor,
+) -> Result<(), DescriptorsError> {
+    let details_type = descriptor.details.base_type();
+    if descriptor.header.base_type != details_type {
+        return Err(DescriptorsError::BaseTypeMismatch(
+            path.to_string(),
+            descriptor.header.base_type.clone(),
+            details_type,
+        ));
+    }
+    match &descriptor.details {
+        ValueDescriptorDetails::Boolean(_) => Ok(()),
+        ValueDescriptorDetails::Composite(composite) => {
+            validate_property_map(path, &composite.property_map)
+        }
+        ValueDescriptorDetails::Enum(enum_descriptor) => validate_enum(path, enum_descriptor),
+        ValueDescriptorDetails::Integer(integer) => {
+            if integer.min_value > integer.max_value {
+                return Err(DescriptorsError::InvalidValueRange(
+                    path.to_string(),
+                    integer.min_value,
+                    integer.max_value,
+                ));
+            }
+            Ok(())
+        }
+        ValueDescriptorDetails::String(string) => {
+            if string.min_length > string.max_length {
+                return Err(DescriptorsError::InvalidLengthRange(
+                    path.to_string(),
+                    string.min_length,
+                    string.max_length,
+                ));
+            }
+            if let Some(pattern) = &string.pattern {
+                if let Err(e) = Regex::new(pattern) {
+                    return Err(DescriptorsError::InvalidPattern(
+                        path.to_string(),
+                        pattern.clone(),
+                        e.to_string(),
+                    ));
+                }
+            }
+            Ok(())
+        }
+        ValueDescriptorDetails::ValueCollection(collection) => {
+            if collection.min_items > collection.max_items {
+                return Err(DescriptorsError::InvalidItemCountRange(
+                    path.to_string(),
+                    collection.min_items,
+                    collection.max_items,
+                ));
+            }
+            let items_path = format!("{path}.items");
+            match &collection.contains_items_of_type {
+                ValueItemType::Shared(reference) => {
+                    validate_holon_reference(&items_path, reference)
+                }
+                ValueItemType::Dedicated(item_descriptor) => {
+                    validate_value_descriptor_at(&items_path, item_descriptor)
+                }
+            }
+        }
+    }
+}
+
+/// A reference to a stored ValueDescriptor made from within another descriptor, along with the
+/// embedded copy of the referenced descriptor (if the referencing site carries one)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedValueReference {
+    pub path: String,
+    pub reference: HolonReference,
+    pub embedded: Option<ValueDescriptor>, // None for collection item types, which carry no copy
+}
+
+/// Collects every reference to a stored ValueDescriptor made within `descriptor` (including
+/// nested composites and dedicated item types). These can't be checked structurally, so the
+/// integrity zome resolves each of them against the DHT.
+pub fn shared_value_references(descriptor: &ValueDescriptor) -> Vec<SharedValueReference> {
+    let mut references = Vec::new();
+    collect_shared_value_references(&descriptor.header.type_name, descriptor, &mut references);
+    references
+}
+
+/// Collects every reference to a stored ValueDescriptor made by the properties of `descriptor`
+pub fn holon_shared_value_references(descriptor: &HolonDescriptor) -> Vec<SharedValueReference> {
+    let mut references = Vec::new();
+    collect_property_map_references(
+        &descriptor.header.type_name,
+        &descriptor.property_map,
+        &mut references,
+    );
+    references
+}
+
+/// Shared usages embed a copy of the descriptor they reference. The copy may lag behind the
+/// stored descriptor's revisions, but it must agree on the identity of the type it copies.
+pub fn validate_embedded_copy(
+    path: &str,
+    embedded: &ValueDescriptor,
+    stored: &ValueDescriptor,
+) -> Result<(), DescriptorsError> {
+    if embedded.header.type_name != stored.header.type_name {
+        return Err(DescriptorsError::SharedDescriptorMismatch(
+            path.to_string(),
+            "type_name".to_string(),
+            embedded.header.type_name.clone(),
+            stored.header.type_name.clone(),
+        ));
+    }
+    if embedded.header.base_type != stored.header.base_type {
+        return Err(DescriptorsError::SharedDescriptorMismatch(
+            path.to_string(),
+            "base_type".to_string(),
+            embedded.header.base_type.to_string(),
+            stored.header.base_type.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that a reference which names a type by type_name identifies a descriptor of that
+/// type_name, so that the name and the ActionHash of the reference cannot disagree
+pub fn validate_reference_name(
+    path: &str,
+    reference: &HolonReference,
+    stored: &TypeHeader,
+) -> Result<(), DescriptorsError> {
+    match &reference.name {
+        Some(name) if name != &stored.type_name => Err(DescriptorsError::ReferenceNameMismatch(
+            path.to_string(),
+            name.clone(),
+            stored.type_name.clone(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn collect_shared_value_references(
+    path: &str,
+    descriptor: &ValueDescriptor,
+    references: &mut Vec<SharedValueReference>,
+) {
+    match &descriptor.details {
+        ValueDescriptorDetails::Composite(composite) => {
+            collect_property_map_references(path, &composite.property_map, references);
+        }
+        ValueDescriptorDetails::ValueCollection(collection) => {
+            let items_path = format!("{path}.items");
+            match &collection.contains_items_of_type {
+                ValueItemType::Shared(reference) => references.push(SharedValueReference {
+                    path: items_path,
+                    reference: reference.clone(),
+                    embedded: None,
+                }),
+                ValueItemType::Dedicated(item_descriptor) => {
+                    collect_shared_value_references(&items_path, item_descriptor, references)
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+// The embedded copy of a shared descriptor is not descended into: the stored descriptor it
+// copies has already had its own references checked.
+fn collect_property_map_references(
+    path: &str,
+    property_map: &PropertyDescriptorMap,
+    references: &mut Vec<SharedValueReference>,
+) {
+    for (property_name, usage) in property_map.properties.iter() {
+        let property_path = format!("{path}.{property_name}");
+        match &usage.sharing {
+            DescriptorSharing::Shared(reference) => references.push(SharedValueReference {
+                path: property_path,
+                reference: reference.clone(),
+                embedded: Some(usage.descriptor.clone()),
+            }),
+            DescriptorSharing::Dedicated => {
+                collect_shared_value_references(&property_path, &usage.descriptor, references)
+            }
+        }
+    }
+}
+
+/// A type may not (transitively) contain itself. These checks walk the descriptors embedded
+/// in composite property maps and dedicated item types, plus the type_names of shared item
+/// types, and fail with DescriptorsError::CyclicTypeGraph naming the types along the cycle
+/// (e.g. `Person -> Address -> Person`). Cycles formed through stored descriptors can only be
+/// found by following shared references on the DHT, which the integrity zome does.
+pub fn validate_acyclic_value_descriptor(
+    descriptor: &ValueDescriptor,
+) -> Result<(), DescriptorsError> {
+    let mut type_names = vec![descriptor.header.type_name.clone()];
+    check_acyclic_details(descriptor, &mut type_names)
+}
+
+pub fn validate_acyclic_holon_descriptor(
+    descriptor: &HolonDescriptor,
+) -> Result<(), DescriptorsError> {
+    let mut type_names = vec![descriptor.header.type_name.clone()];
+    for usage in descriptor.property_map.properties.values() {
+        check_acyclic_at(&usage.descriptor, &mut type_names)?;
+    }
+    Ok(())
+}
+
+/// Returns the cycle closed by `type_name` if it is already among the `type_names` being walked
+pub fn find_cycle(type_names: &[String], type_name: &str) -> Option<Vec<String>> {
+    type_names
+        .iter()
+        .position(|walked| walked == type_name)
+        .map(|start| {
+            let mut cycle = type_names[start..].to_vec();
+            cycle.push(type_name.to_string());
+            cycle
+        })
+}
+
+fn check_acyclic_at(
+    descriptor: &ValueDescriptor,
+    type_names: &mut Vec<String>,
+) -> Result<(), DescriptorsError> {
+    if let Some(cycle) = find_cycle(type_names, &descriptor.header.type_name) {
+        return Err(DescriptorsError::CyclicTypeGraph(cycle));
+    }
+    type_names.push(descriptor.header.type_name.clone());
+    let result = check_acyclic_details(descriptor, type_names);
+    type_names.pop();
+    result
+}
+
+fn check_acyclic_details(
+    descriptor: &ValueDescriptor,
+    type_names: &mut Vec<String>,
+) -> Result<(), DescriptorsError> {
+    match &descriptor.details {
+        ValueDescriptorDetails::Composite(composite) => {
+            for usage in composite.property_map.properties.values() {
+                check_acyclic_at(&usage.descriptor, type_names)?;
+            }
+            Ok(())
+        }
+        ValueDescriptorDetails::ValueCollection(collection) => {
+            match &collection.contains_items_of_type {
+                ValueItemType::Dedicated(item_descriptor) => {
+                    check_acyclic_at(item_descriptor, type_names)
+                }
+                ValueItemType::Shared(reference) => {
+                    let cycle = reference
+                        .name
+                        .as_ref()
+                        .and_then(|name| find_cycle(type_names, name));
+                    match cycle {
+                        Some(cycle) => Err(DescriptorsError::CyclicTypeGraph(cycle)),
+                        None => Ok(()),
+                    }
+                }
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_holon_reference(
+    path: &str,
+    reference: &HolonReference,
+) -> Result<(), DescriptorsError> {
+    if reference.id.is_none() {
+        return Err(DescriptorsError::UnresolvedReference(path.to_string()));
+    }
+    Ok(())
+}
+
+fn validate_enum(path: &str, enum_descriptor: &EnumDescriptor) -> Result<(), DescriptorsError> {
+    if enum_descriptor.variants.is_empty() {
+        return Err(DescriptorsError::EmptyEnum(path.to_string()));
+    }
+    let mut names = BTreeSet::new();
+    let mut codes = BTreeSet::new();
+    for variant in enum_descriptor.variants.iter() {
+        if variant.name.is_empty() {
+            return Err(DescriptorsError::EmptyField(format!("{path} variant name")));
+        }
+        if !names.insert(variant.name.clone()) {
+            return Err(DescriptorsError::DuplicateEnumVariant(
+                path.to_string(),
+                variant.name.clone(),
+            ));
+        }
+        if let Some(code) = variant.code {
+            if !codes.insert(code) {
+                return Err(DescriptorsError::DuplicateEnumCode(path.to_string(), code));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_property_map(
+    path: &str,
+    property_map: &PropertyDescriptorMap,
+) -> Result<(), DescriptorsError> {
+    for (property_name, usage) in property_map.properties.iter() {
+        validate_value_descriptor_at(&format!("{path}.{property_name}"), &usage.descriptor)?;
+    }
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::holon_descriptor::HolonReference;
-use crate::type_header::TypeHeader;
+use crate::type_header::{BaseType, TypeHeader};
 use derive_new::new;
 use hdi::prelude::*;
 use std::collections::BTreeMap;
@@ -48,12 +48,26 @@ pub struct PropertyDescriptorMap {
 pub enum ValueDescriptorDetails {
     Boolean(BooleanDescriptor),
     Composite(CompositeDescriptor),
-    //Enum(EnumDescriptor),
+    Enum(EnumDescriptor),
     Integer(IntegerDescriptor),
     String(StringDescriptor),
     ValueCollection(ValueCollectionDescriptor), // can only contain collections of PropertyTypes (not Holons)
 }
 
+impl ValueDescriptorDetails {
+    /// Returns the BaseType that a TypeHeader must declare for a descriptor with these details
+    pub fn base_type(&self) -> BaseType {
+        match self {
+            ValueDescriptorDetails::Boolean(_) => BaseType::Boolean,
+            ValueDescriptorDetails::Composite(_) => BaseType::Composite,
+            ValueDescriptorDetails::Enum(_) => BaseType::Enum,
+            ValueDescriptorDetails::Integer(_) => BaseType::Integer,
+            ValueDescriptorDetails::String(_) => BaseType::String,
+            ValueDescriptorDetails::ValueCollection(_) => BaseType::Collection,
+        }
+    }
+}
+
 #[hdk_entry_helper]
 #[derive(new, Default, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -68,6 +82,25 @@ pub struct CompositeDescriptor {
     pub property_map: PropertyDescriptorMap,
 }
 
+/// EnumDescriptor describes a value that must be one of an ordered list of named variants.
+/// The order of the variants is significant (e.g., Low < Medium < High).
+#[hdk_entry_helper]
+#[derive(new, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnumDescriptor {
+    pub variants: Vec<EnumVariant>,
+}
+
+#[hdk_entry_helper]
+#[derive(new, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnumVariant {
+    pub name: String, // identifies the variant, must be unique within its EnumDescriptor
+    pub label: String,
+    pub description: String,
+    pub code: Option<i64>, // optional numeric code, e.g. for interop with external systems
+}
+
 #[hdk_entry_helper]
 #[derive(new, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -83,21 +116,42 @@ pub struct IntegerDescriptor {
 pub struct StringDescriptor {
     pub min_length: u32,
     pub max_length: u32,
-    //pattern: String,
+    #[new(default)]
+    pub pattern: Option<String>, // regular expression that values must (fully) match
+    #[new(default)]
+    pub format: Option<StringFormat>,
 }
 
-// This is just a first cut at ValueCollectionDescriptor
-// It identifies the kinds of items the collection contains via a string
+/// StringFormat identifies a well-known string format. Unlike `pattern`, it is a hint to
+/// clients about how to parse, render and check values, rather than a literal expression.
+#[hdk_entry_helper]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum StringFormat {
+    Email,
+    Uri,
+    Uuid,
+    Date,           // ISO 8601 calendar date (YYYY-MM-DD)
+    IsoCountryCode, // ISO 3166-1 alpha-2
+}
 
 #[hdk_entry_helper]
 #[derive(new, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct ValueCollectionDescriptor {
-    pub contains_items_of_type: String,
-    // TODO: replace this with a ref
+    pub contains_items_of_type: ValueItemType,
     pub min_items: u32,
     pub max_items: u32,
     pub unique_items: bool,
     // true means duplicate items are not allowed
     pub is_ordered: bool, // if items have an intrinsic order
 }
+
+/// ValueItemType identifies the type of the items in a ValueCollectionDescriptor: either a
+/// stored ValueDescriptor, referenced by ActionHash (and type_name), or a dedicated descriptor
+/// embedded in the collection itself.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq, Eq)]
+pub enum ValueItemType {
+    Shared(HolonReference),
+    Dedicated(Box<ValueDescriptor>),
+}
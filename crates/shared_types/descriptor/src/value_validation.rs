@@ -0,0 +1,313 @@
+use crate::base_value::{BaseValue, FUZZY_TRUE};
+use crate::holon_descriptor::HolonDescriptor;
+use crate::type_header::BaseType;
+use crate::value_descriptor::{
+    BooleanDescriptor, EnumDescriptor, IntegerDescriptor, PropertyDescriptorMap,
+    StringDescriptor, StringFormat, ValueCollectionDescriptor, ValueDescriptor,
+    ValueDescriptorDetails, ValueItemType,
+};
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+use thiserror::Error;
+
+/// Checks values against the ValueDescriptors that describe them. Like the structural checks in
+/// `validation`, these functions are pure and can run in either zome or in native Rust.
+///
+/// Rather than stopping at the first problem, every violation found is collected and returned.
+/// Each one is addressed by a dotted path that starts at the descriptor's type_name and follows
+/// property names down through nested composites (e.g. `Person.address.street`), and uses
+/// `[index]` for the items of a collection (e.g. `Person.nicknames[2]`).
+
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum ValueViolation {
+    #[error("{0}: expected a {1} value, found {2}")]
+    KindMismatch(String, BaseType, String),
+    #[error("{0}: length {1} is outside the allowed range [{2}, {3}]")]
+    LengthOutOfRange(String, u32, u32, u32),
+    #[error("{0}: value does not match pattern {1}")]
+    PatternMismatch(String, String),
+    #[error("{0}: value is not a valid {1:?}")]
+    FormatMismatch(String, StringFormat),
+    #[error("{0}: value {1} is outside the allowed range [{2}, {3}]")]
+    ValueOutOfRange(String, i64, i64, i64),
+    #[error("{0}: fuzzy truth value {1} is greater than {2}")]
+    InvalidFuzzyValue(String, u8, u8),
+    #[error("{0}: {1} is not a variant of this enum")]
+    UnknownEnumVariant(String, String),
+    #[error("{0}: {1} items is outside the allowed range [{2}, {3}]")]
+    ItemCountOutOfRange(String, u32, u32, u32),
+    #[error("{0}: collection requires unique items, but item {1} is a duplicate")]
+    DuplicateItem(String, u32),
+    #[error("{0}: property {1} is not described by the descriptor")]
+    UnknownProperty(String, String),
+    #[error("{0}: property {1} has no value")]
+    MissingProperty(String, String),
+}
+
+pub fn validate_value(
+    descriptor: &ValueDescriptor,
+    value: &BaseValue,
+) -> Result<(), Vec<ValueViolation>> {
+    let mut violations = Vec::new();
+    check_value(&descriptor.header.type_name, descriptor, value, &mut violations);
+    into_result(violations)
+}
+
+/// Checks a set of (property_name, value) pairs against a property map, e.g. the properties of
+/// a holon against its HolonDescriptor. Properties that have no value are allowed.
+pub fn validate_property_values(
+    path: &str,
+    property_map: &PropertyDescriptorMap,
+    values: &BTreeMap<String, BaseValue>,
+) -> Result<(), Vec<ValueViolation>> {
+    let mut violations = Vec::new();
+    check_property_values(path, property_map, values, &mut violations);
+    into_result(violations)
+}
+
+/// Checks the property values of a holon against its HolonDescriptor. Unlike the properties of
+/// a composite value, every property described by the HolonDescriptor must have a value.
+pub fn validate_holon_property_values(
+    descriptor: &HolonDescriptor,
+    values: &BTreeMap<String, BaseValue>,
+) -> Result<(), Vec<ValueViolation>> {
+    let path = &descriptor.header.type_name;
+    let mut violations = Vec::new();
+    for property_name in descriptor.property_map.properties.keys() {
+        if !values.contains_key(property_name) {
+            violations.push(ValueViolation::MissingProperty(
+                path.to_string(),
+                property_name.clone(),
+            ));
+        }
+    }
+    check_property_values(path, &descriptor.property_map, values, &mut violations);
+    into_result(violations)
+}
+
+fn into_result(violations: Vec<ValueViolation>) -> Result<(), Vec<ValueViolation>> {
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+fn check_value(
+    path: &str,
+    descriptor: &ValueDescriptor,
+    value: &BaseValue,
+    violations: &mut Vec<ValueViolation>,
+) {
+    match (&descriptor.details, value) {
+        (ValueDescriptorDetails::Boolean(boolean), _) => {
+            check_boolean(path, boolean, value, violations)
+        }
+        (ValueDescriptorDetails::Composite(composite), BaseValue::Composite(values)) => {
+            check_property_values(path, &composite.property_map, values, violations)
+        }
+        (ValueDescriptorDetails::Enum(enum_descriptor), BaseValue::Enum(name)) => {
+            check_enum(path, enum_descriptor, name, violations)
+        }
+        (ValueDescriptorDetails::Integer(integer), BaseValue::Integer(n)) => {
+            check_integer(path, integer, *n, violations)
+        }
+        (ValueDescriptorDetails::String(string), BaseValue::String(s)) => {
+            check_string(path, string, s, violations)
+        }
+        (ValueDescriptorDetails::ValueCollection(collection), BaseValue::Collection(items)) => {
+            check_collection(path, collection, items, violations)
+        }
+        (details, value) => violations.push(ValueViolation::KindMismatch(
+            path.to_string(),
+            details.base_type(),
+            value.kind().to_string(),
+        )),
+    }
+}
+
+fn check_boolean(
+    path: &str,
+    boolean: &BooleanDescriptor,
+    value: &BaseValue,
+    violations: &mut Vec<ValueViolation>,
+) {
+    match value {
+        BaseValue::Boolean(_) => {}
+        BaseValue::FuzzyBoolean(degree) if boolean.is_fuzzy => {
+            if *degree > FUZZY_TRUE {
+                violations.push(ValueViolation::InvalidFuzzyValue(
+                    path.to_string(),
+                    *degree,
+                    FUZZY_TRUE,
+                ));
+            }
+        }
+        _ => violations.push(ValueViolation::KindMismatch(
+            path.to_string(),
+            BaseType::Boolean,
+            value.kind().to_string(),
+        )),
+    }
+}
+
+fn check_enum(
+    path: &str,
+    enum_descriptor: &EnumDescriptor,
+    name: &str,
+    violations: &mut Vec<ValueViolation>,
+) {
+    if !enum_descriptor
+        .variants
+        .iter()
+        .any(|variant| variant.name == name)
+    {
+        violations.push(ValueViolation::UnknownEnumVariant(
+            path.to_string(),
+            name.to_string(),
+        ));
+    }
+}
+
+fn check_integer(
+    path: &str,
+    integer: &IntegerDescriptor,
+    n: i64,
+    violations: &mut Vec<ValueViolation>,
+) {
+    if n < integer.min_value || n > integer.max_value {
+        violations.push(ValueViolation::ValueOutOfRange(
+            path.to_string(),
+            n,
+            integer.min_value,
+            integer.max_value,
+        ));
+    }
+}
+
+fn check_string(
+    path: &str,
+    string: &StringDescriptor,
+    s: &str,
+    violations: &mut Vec<ValueViolation>,
+) {
+    let length = s.chars().count() as u32;
+    if length < string.min_length || length > string.max_length {
+        violations.push(ValueViolation::LengthOutOfRange(
+            path.to_string(),
+            length,
+            string.min_length,
+            string.max_length,
+        ));
+    }
+    if let Some(pattern) = &string.pattern {
+        // the pattern must match the whole value, not just some part of it
+        let matches = Regex::new(&format!("^(?:{pattern})$"))
+            .map(|regex| regex.is_match(s))
+            .unwrap_or(false);
+        if !matches {
+            violations.push(ValueViolation::PatternMismatch(
+                path.to_string(),
+                pattern.clone(),
+            ));
+        }
+    }
+    if let Some(format) = string.format {
+        if !matches_format(format, s) {
+            violations.push(ValueViolation::FormatMismatch(path.to_string(), format));
+        }
+    }
+}
+
+// These are deliberately lenient checks of the general shape of each format, not full parsers.
+fn matches_format(format: StringFormat, s: &str) -> bool {
+    let pattern = match format {
+        StringFormat::Email => r"^[^@\s]+@[^@\s]+\.[^@\s]+$",
+        StringFormat::Uri => r"^[A-Za-z][A-Za-z0-9+.\-]*:\S+$",
+        StringFormat::Uuid => {
+            r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+        }
+        StringFormat::Date => return is_iso_date(s),
+        StringFormat::IsoCountryCode => r"^[A-Z]{2}$",
+    };
+    Regex::new(pattern)
+        .map(|regex| regex.is_match(s))
+        .unwrap_or(false)
+}
+
+fn is_iso_date(s: &str) -> bool {
+    let parts: Vec<&str> = s.split('-').collect();
+    if parts.len() != 3
+        || parts[0].len() != 4
+        || parts[1].len() != 2
+        || parts[2].len() != 2
+        || !parts.iter().all(|part| part.chars().all(|c| c.is_ascii_digit()))
+    {
+        return false;
+    }
+    let year: u32 = parts[0].parse().unwrap_or(0);
+    let month: u32 = parts[1].parse().unwrap_or(0);
+    let day: u32 = parts[2].parse().unwrap_or(0);
+    let is_leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days_in_month).contains(&day)
+}
+
+// Items are only checked against a dedicated item type; a shared item type is only known by
+// reference, and resolving it is up to the caller.
+fn check_collection(
+    path: &str,
+    collection: &ValueCollectionDescriptor,
+    items: &[BaseValue],
+    violations: &mut Vec<ValueViolation>,
+) {
+    let count = items.len() as u32;
+    if count < collection.min_items || count > collection.max_items {
+        violations.push(ValueViolation::ItemCountOutOfRange(
+            path.to_string(),
+            count,
+            collection.min_items,
+            collection.max_items,
+        ));
+    }
+    if collection.unique_items {
+        let mut seen = BTreeSet::new();
+        for (index, item) in items.iter().enumerate() {
+            if !seen.insert(item) {
+                violations.push(ValueViolation::DuplicateItem(
+                    path.to_string(),
+                    index as u32,
+                ));
+            }
+        }
+    }
+    if let ValueItemType::Dedicated(item_descriptor) = &collection.contains_items_of_type {
+        for (index, item) in items.iter().enumerate() {
+            check_value(&format!("{path}[{index}]"), item_descriptor, item, violations);
+        }
+    }
+}
+
+fn check_property_values(
+    path: &str,
+    property_map: &PropertyDescriptorMap,
+    values: &BTreeMap<String, BaseValue>,
+    violations: &mut Vec<ValueViolation>,
+) {
+    for (property_name, value) in values.iter() {
+        let property_path = format!("{path}.{property_name}");
+        match property_map.properties.get(property_name) {
+            Some(usage) => check_value(&property_path, &usage.descriptor, value, violations),
+            None => violations.push(ValueViolation::UnknownProperty(
+                path.to_string(),
+                property_name.clone(),
+            )),
+        }
+    }
+}
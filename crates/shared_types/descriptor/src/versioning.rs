@@ -0,0 +1,343 @@
+use hdi::prelude::*;
+use std::fmt;
+
+use crate::error::DescriptorsError;
+use crate::holon_descriptor::{HolonCollectionDescriptor, HolonDescriptor};
+use crate::relationship_descriptor::RelationshipDescriptor;
+use crate::type_header::{SemanticVersion, TypeHeader};
+use crate::value_descriptor::{
+    EnumDescriptor, PropertyDescriptorMap, ValueDescriptor, ValueDescriptorDetails, ValueItemType,
+};
+
+/// ChangeKind classifies the difference between two revisions of a descriptor by its impact on
+/// existing instances, and therefore by which part of the SemanticVersion must be bumped:
+/// - Major: some previously valid instance may no longer be valid (e.g., a narrowed range, a
+///   removed property, or a property added to a holon)
+/// - Minor: every previously valid instance remains valid, but new ones are possible (e.g., a
+///   widened range, an added enum variant, or a property added to a composite value)
+/// - Patch: only documentation changed (e.g., label or description edits)
+///
+/// Every update is at least a Patch change, even if nothing but the version differs.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeKind {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChangeKind::Patch => write!(f, "patch"),
+            ChangeKind::Minor => write!(f, "minor"),
+            ChangeKind::Major => write!(f, "major"),
+        }
+    }
+}
+
+/// Returns the smallest version that a `change` to a descriptor at version `current` may use
+pub fn next_version(
+    current: &SemanticVersion,
+    change: ChangeKind,
+) -> Result<SemanticVersion, DescriptorsError> {
+    let overflow = || DescriptorsError::VersionOverflow(current.clone(), change);
+    let next = match change {
+        ChangeKind::Major => {
+            SemanticVersion::new(current.major().checked_add(1).ok_or_else(overflow)?, 0, 0)
+        }
+        ChangeKind::Minor => SemanticVersion::new(
+            current.major(),
+            current.minor().checked_add(1).ok_or_else(overflow)?,
+            0,
+        ),
+        ChangeKind::Patch => SemanticVersion::new(
+            current.major(),
+            current.minor(),
+            current.patch().checked_add(1).ok_or_else(overflow)?,
+        ),
+    };
+    Ok(next)
+}
+
+/// Verifies that `updated` is at least the next version of `original` required by `change`
+pub fn validate_version_bump(
+    original: &SemanticVersion,
+    updated: &SemanticVersion,
+    change: ChangeKind,
+) -> Result<(), DescriptorsError> {
+    let required = next_version(original, change)?;
+    if *updated < required {
+        return Err(DescriptorsError::InsufficientVersionBump(
+            original.clone(),
+            updated.clone(),
+            change,
+        ));
+    }
+    Ok(())
+}
+
+pub fn classify_holon_descriptor_change(
+    original: &HolonDescriptor,
+    updated: &HolonDescriptor,
+) -> ChangeKind {
+    classify_header_change(&original.header, &updated.header)
+        .max(classify_property_map_change(
+            &original.property_map,
+            &updated.property_map,
+            ChangeKind::Major,
+        ))
+}
+
+pub fn classify_value_descriptor_change(
+    original: &ValueDescriptor,
+    updated: &ValueDescriptor,
+) -> ChangeKind {
+    classify_header_change(&original.header, &updated.header)
+        .max(classify_details_change(&original.details, &updated.details))
+}
+
+// Changing the item type or ordering is Major; the item count range and unique_items are
+// classified like those of a ValueCollectionDescriptor.
+pub fn classify_holon_collection_descriptor_change(
+    original: &HolonCollectionDescriptor,
+    updated: &HolonCollectionDescriptor,
+) -> ChangeKind {
+    if original.contains_items_of_type != updated.contains_items_of_type
+        || original.is_ordered != updated.is_ordered
+    {
+        return ChangeKind::Major;
+    }
+    classify_header_change(&original.header, &updated.header)
+        .max(classify_bounds_change(
+            original.min_items,
+            updated.min_items,
+            original.max_items,
+            updated.max_items,
+        ))
+        .max(classify_bounds_change(
+            original.unique_items,
+            updated.unique_items,
+            !original.unique_items,
+            !updated.unique_items,
+        ))
+}
+
+// Retargeting or renaming the inverse of the relationship, or changing its order or deletion
+// semantics, is Major; the target cardinality is classified like any other range.
+pub fn classify_relationship_descriptor_change(
+    original: &RelationshipDescriptor,
+    updated: &RelationshipDescriptor,
+) -> ChangeKind {
+    if original.source_type != updated.source_type
+        || original.target_type != updated.target_type
+        || original.inverse_name != updated.inverse_name
+        || original.is_ordered != updated.is_ordered
+        || original.deletion_semantic != updated.deletion_semantic
+    {
+        return ChangeKind::Major;
+    }
+    classify_header_change(&original.header, &updated.header).max(classify_bounds_change(
+        original.min_target_cardinality,
+        updated.min_target_cardinality,
+        original.max_target_cardinality,
+        updated.max_target_cardinality,
+    ))
+}
+
+// The version itself is ignored; identity fields are immutable, but are classified as Major
+// so that classification never depends on validation having run first.
+fn classify_header_change(original: &TypeHeader, updated: &TypeHeader) -> ChangeKind {
+    if original.type_name != updated.type_name
+        || original.base_type != updated.base_type
+        || original.is_dependent != updated.is_dependent
+    {
+        return ChangeKind::Major;
+    }
+    ChangeKind::Patch
+}
+
+fn classify_details_change(
+    original: &ValueDescriptorDetails,
+    updated: &ValueDescriptorDetails,
+) -> ChangeKind {
+    match (original, updated) {
+        (ValueDescriptorDetails::Boolean(original), ValueDescriptorDetails::Boolean(updated)) => {
+            classify_bounds_change(
+                !original.is_fuzzy,
+                !updated.is_fuzzy,
+                original.is_fuzzy,
+                updated.is_fuzzy,
+            )
+        }
+        (
+            ValueDescriptorDetails::Composite(original),
+            ValueDescriptorDetails::Composite(updated),
+        ) => classify_property_map_change(
+            &original.property_map,
+            &updated.property_map,
+            ChangeKind::Minor,
+        ),
+        (ValueDescriptorDetails::Enum(original), ValueDescriptorDetails::Enum(updated)) => {
+            classify_enum_change(original, updated)
+        }
+        (ValueDescriptorDetails::Integer(original), ValueDescriptorDetails::Integer(updated)) => {
+            classify_bounds_change(
+                original.min_value,
+                updated.min_value,
+                original.max_value,
+                updated.max_value,
+            )
+        }
+        (ValueDescriptorDetails::String(original), ValueDescriptorDetails::String(updated)) => {
+            classify_bounds_change(
+                original.min_length,
+                updated.min_length,
+                original.max_length,
+                updated.max_length,
+            )
+            .max(classify_constraint_change(&original.pattern, &updated.pattern))
+            .max(classify_constraint_change(&original.format, &updated.format))
+        }
+        (
+            ValueDescriptorDetails::ValueCollection(original),
+            ValueDescriptorDetails::ValueCollection(updated),
+        ) => {
+            if original.is_ordered != updated.is_ordered {
+                return ChangeKind::Major;
+            }
+            classify_item_type_change(
+                &original.contains_items_of_type,
+                &updated.contains_items_of_type,
+            )
+            .max(classify_bounds_change(
+                original.min_items,
+                updated.min_items,
+                original.max_items,
+                updated.max_items,
+            ))
+            .max(classify_bounds_change(
+                original.unique_items,
+                updated.unique_items,
+                !original.unique_items,
+                !updated.unique_items,
+            ))
+        }
+        _ => ChangeKind::Major,
+    }
+}
+
+// Switching between shared and dedicated item types, or to another shared type, is Major;
+// a dedicated item type is classified like any other value descriptor.
+fn classify_item_type_change(original: &ValueItemType, updated: &ValueItemType) -> ChangeKind {
+    match (original, updated) {
+        (ValueItemType::Shared(original), ValueItemType::Shared(updated))
+            if original == updated =>
+        {
+            ChangeKind::Patch
+        }
+        (ValueItemType::Dedicated(original), ValueItemType::Dedicated(updated)) => {
+            classify_value_descriptor_change(original, updated)
+        }
+        _ => ChangeKind::Major,
+    }
+}
+
+// An optional constraint (e.g. a string pattern) that is added or replaced may reject values
+// that were previously valid, so it is Major; dropping it only admits more values (Minor).
+fn classify_constraint_change<T: PartialEq>(
+    original: &Option<T>,
+    updated: &Option<T>,
+) -> ChangeKind {
+    match (original, updated) {
+        (None, None) => ChangeKind::Patch,
+        (Some(_), None) => ChangeKind::Minor,
+        (Some(original), Some(updated)) if original == updated => ChangeKind::Patch,
+        _ => ChangeKind::Major,
+    }
+}
+
+// Removing a variant, changing its code or reordering the variants is Major; adding a variant
+// is Minor; editing a variant's label or description is a Patch.
+fn classify_enum_change(original: &EnumDescriptor, updated: &EnumDescriptor) -> ChangeKind {
+    let mut retained_names = Vec::new();
+    for original_variant in original.variants.iter() {
+        match updated
+            .variants
+            .iter()
+            .find(|variant| variant.name == original_variant.name)
+        {
+            Some(updated_variant) => {
+                if updated_variant.code != original_variant.code {
+                    return ChangeKind::Major;
+                }
+                retained_names.push(&original_variant.name);
+            }
+            None => return ChangeKind::Major,
+        }
+    }
+    let updated_order: Vec<&String> = updated
+        .variants
+        .iter()
+        .map(|variant| &variant.name)
+        .filter(|name| retained_names.contains(name))
+        .collect();
+    if updated_order != retained_names {
+        return ChangeKind::Major;
+    }
+    if updated.variants.len() > original.variants.len() {
+        ChangeKind::Minor
+    } else {
+        ChangeKind::Patch
+    }
+}
+
+// Classifies a change to an inclusive [min, max] range: narrowing it is Major, widening it is
+// Minor. Boolean flags are treated as a range too: [!flag, flag] for flags that admit more
+// values when set (is_fuzzy), [flag, !flag] for flags that admit fewer (unique_items).
+fn classify_bounds_change<T: PartialOrd>(
+    original_min: T,
+    updated_min: T,
+    original_max: T,
+    updated_max: T,
+) -> ChangeKind {
+    if updated_min > original_min || updated_max < original_max {
+        ChangeKind::Major
+    } else if updated_min < original_min || updated_max > original_max {
+        ChangeKind::Minor
+    } else {
+        ChangeKind::Patch
+    }
+}
+
+// Removing a property (or changing how it is shared) is Major. Adding one is classified as
+// `added_property`: Major for a holon, every property of which must be given, but Minor for a
+// composite value, whose properties may be left without a value.
+fn classify_property_map_change(
+    original: &PropertyDescriptorMap,
+    updated: &PropertyDescriptorMap,
+    added_property: ChangeKind,
+) -> ChangeKind {
+    let mut change = ChangeKind::Patch;
+    for (property_name, original_usage) in original.properties.iter() {
+        match updated.properties.get(property_name) {
+            Some(updated_usage) => {
+                if original_usage.sharing != updated_usage.sharing {
+                    return ChangeKind::Major;
+                }
+                change = change.max(classify_value_descriptor_change(
+                    &original_usage.descriptor,
+                    &updated_usage.descriptor,
+                ));
+            }
+            None => return ChangeKind::Major,
+        }
+    }
+    if updated
+        .properties
+        .keys()
+        .any(|property_name| !original.properties.contains_key(property_name))
+    {
+        change = change.max(added_property);
+    }
+    change
+}